@@ -1,5 +1,3 @@
-use srzs_lang;
-
 fn main() {
     srzs_lang::load_source("tests/let_statements.srzs");
 }
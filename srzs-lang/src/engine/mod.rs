@@ -0,0 +1,336 @@
+use crate::{library, module, runtime};
+use std::cell::RefCell;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use utils::backend::{bytecode, compiler, operations};
+use utils::frontend::*;
+use utils::handlers;
+use utils::types;
+use utils::types::built_in_types::BuiltInTypes;
+
+/// A script engine, globals and functions of every evaluated source stay available to the next ones
+#[derive(Debug)]
+pub struct Engine {
+    compiler: compiler::Compiler,
+    runtime: runtime::Runtime,
+    /// Streams used by "std::io", shared with its natives
+    streams: Rc<RefCell<library::io::Streams>>,
+    /// Directories where imported modules are searched, in order
+    search_paths: Vec<PathBuf>,
+    /// Names of the linked modules
+    modules: Vec<String>,
+    /// Names of the modules being loaded, a module that imports one of them is a circular import
+    loading: Vec<String>,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine {
+    /// Create a engine with the standard library
+    pub fn new() -> Self {
+        let mut engine = Self {
+            compiler: compiler::Compiler::new(),
+            runtime: runtime::Runtime::new(),
+            streams: Rc::new(RefCell::new(library::io::Streams::default())),
+            search_paths: vec![PathBuf::from(".")],
+            modules: vec![],
+            loading: vec![],
+        };
+
+        library::register(&mut engine);
+
+        engine
+    }
+
+    /// The streams of "std::io", natives that read or write them keep a reference
+    pub(crate) fn streams(&self) -> Rc<RefCell<library::io::Streams>> {
+        Rc::clone(&self.streams)
+    }
+
+    /// Replace where "std::io" prints, the standard output by default
+    pub fn set_output<W: Write + 'static>(&mut self, output: W) {
+        self.streams.borrow_mut().output = Box::new(output);
+    }
+
+    /// Replace where "std::io" prints errors, the standard error by default
+    pub fn set_error_output<W: Write + 'static>(&mut self, error: W) {
+        self.streams.borrow_mut().error = Box::new(error);
+    }
+
+    /// Replace where "std::io" reads lines, the standard input by default
+    pub fn set_input<R: BufRead + 'static>(&mut self, input: R) {
+        self.streams.borrow_mut().input = Box::new(input);
+    }
+
+    /// Add a directory where the modules imported with "use" are searched
+    pub fn add_search_path<P: AsRef<Path>>(&mut self, path: P) {
+        self.search_paths.push(path.as_ref().to_path_buf());
+    }
+
+    /// Compile and run a source, nothing is kept from it when it fails
+    ///
+    /// The globals get back the values they had before it, what its natives did, like printing, stays
+    pub fn eval_str(&mut self, source: &str) -> Result<(), Vec<handlers::error_handler::Error>> {
+        // Restored if the source, or a module it imports, can't be compiled or run
+        let compiler = self.compiler.clone();
+        let checkpoint = self.runtime.checkpoint();
+        let modules = self.modules.len();
+
+        let result = self.eval_module(source, None);
+
+        if result.is_err() {
+            self.compiler = compiler;
+            self.runtime.rollback(checkpoint);
+            self.modules.truncate(modules);
+            self.loading.clear();
+        }
+
+        result
+    }
+
+    /// Read a file and evaluate it as a source, its modules are searched next to it first
+    pub fn eval_file(&mut self, path: &str) -> Result<(), Vec<handlers::error_handler::Error>> {
+        let data = read_source(Path::new(path)).map_err(|error| vec![error])?;
+        let directory = Path::new(path).parent().map(Path::to_path_buf);
+
+        if let Some(directory) = &directory {
+            self.search_paths.insert(0, directory.to_owned());
+        }
+
+        let result = self.eval_str(&data);
+
+        if directory.is_some() {
+            self.search_paths.remove(0);
+        }
+
+        result
+    }
+
+    /// Compile and run a source, a module when it has a name
+    fn eval_module(
+        &mut self,
+        source: &str,
+        name: Option<&str>,
+    ) -> Result<(), Vec<handlers::error_handler::Error>> {
+        let mut lexer = lexer::Lexer::new();
+        lexer.lex(source);
+
+        let mut parser = parser::Parser::new(lexer.tokens);
+        parser.parse_tokens();
+
+        let errors: Vec<_> = lexer.errors.into_iter().chain(parser.errors).collect();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // Imported modules are linked before the source that uses them is compiled
+        self.load_imports(&parser.ast)?;
+
+        let bytecode = match name {
+            Some(name) => self.compiler.compile_module(&parser.ast, name),
+            None => self.compiler.compile(&parser.ast),
+        };
+
+        // Report every error found before running anything
+        let errors = std::mem::take(&mut self.compiler.errors);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        match name {
+            Some(name) => self.link(module::Module::new(name, bytecode)),
+            None => self.runtime.run_byte(bytecode).map_err(|error| vec![error]),
+        }
+    }
+
+    /// Load the modules of the use statements that aren't known yet
+    ///
+    /// The longest path with a file is the module -> use a::b::c; <- "a/b/c.srzs", or the item
+    /// "c" of "a/b.srzs"
+    fn load_imports(
+        &mut self,
+        ast: &types::parse_nodes::Statement,
+    ) -> Result<(), Vec<handlers::error_handler::Error>> {
+        let body = match ast {
+            types::parse_nodes::Statement::Module { body, .. } => body,
+            _ => return Ok(()),
+        };
+
+        for statement in body.iter() {
+            let path = match statement {
+                types::parse_nodes::Statement::Use { path, .. } => path,
+                _ => continue,
+            };
+
+            if self.compiler.is_known_path(path) {
+                continue;
+            }
+
+            let segments: Vec<&str> = path.split("::").collect();
+
+            for length in (1..=segments.len()).rev() {
+                let name = segments[..length].join("::");
+
+                if self.modules.contains(&name) {
+                    break;
+                }
+
+                let file = self.search_paths.iter().find_map(|directory| {
+                    let mut file = directory.join(segments[..length].join("/"));
+                    file.set_extension("srzs");
+                    file.is_file().then_some(file)
+                });
+
+                // A path without file is reported by the compiler as unresolved
+                if let Some(file) = file {
+                    self.load_module(&name, &file)?;
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compile a module file and link it
+    fn load_module(
+        &mut self,
+        name: &str,
+        file: &Path,
+    ) -> Result<(), Vec<handlers::error_handler::Error>> {
+        if self.loading.iter().any(|module| module == name) {
+            return Err(vec![engine_error(
+                &format!(
+                    "circular import -> {} -> {}.",
+                    self.loading.join(" -> "),
+                    name
+                ),
+                handlers::error_handler::ErrorCode::E022,
+            )]);
+        }
+
+        let source = read_source(file).map_err(|error| vec![error])?;
+
+        self.loading.push(name.to_string());
+        let result = self.eval_module(&source, Some(name));
+        self.loading.pop();
+
+        result
+    }
+
+    /// Run the global code of a module and make its items available
+    fn link(&mut self, module: module::Module) -> Result<(), Vec<handlers::error_handler::Error>> {
+        self.runtime
+            .run_byte(module.bytecode)
+            .map_err(|error| vec![error])?;
+        self.modules.push(module.name);
+
+        Ok(())
+    }
+
+    /// Register a host function callable from scripts by its path, like "io::println"
+    ///
+    /// The arguments are converted to the parameter types before the call, and the value to
+    /// the return type after it, a int is accepted where a float is expected
+    pub fn register_native<F>(
+        &mut self,
+        path: &str,
+        params: Vec<BuiltInTypes>,
+        returns: BuiltInTypes,
+        function: F,
+    ) where
+        F: Fn(Vec<bytecode::Value>) -> Result<bytecode::Value, String> + 'static,
+    {
+        self.compiler
+            .declare_native(path, params.to_owned(), returns.to_owned());
+        self.runtime
+            .register_native(path, params, returns, Rc::new(function));
+    }
+
+    /// Register a host value readable from scripts by its path, like "math::PI"
+    pub fn register_constant(&mut self, path: &str, value: bytecode::Value) {
+        self.compiler.declare_constant(path, value);
+    }
+
+    /// Call a script function by its name and return its value
+    pub fn call_function(
+        &mut self,
+        name: &str,
+        args: Vec<bytecode::Value>,
+    ) -> Result<bytecode::Value, handlers::error_handler::Error> {
+        match self.compiler.function_id(name) {
+            Some(id) => self.runtime.call(id, args),
+            None => Err(engine_error(
+                &format!("the function \"{}\" is not defined.", name),
+                handlers::error_handler::ErrorCode::E005,
+            )),
+        }
+    }
+
+    /// Return the value of a global variable
+    pub fn get_global(&self, name: &str) -> Option<bytecode::Value> {
+        self.compiler
+            .global_slot(name)
+            .and_then(|(slot, _)| self.runtime.global(slot))
+            .cloned()
+    }
+
+    /// Replace the value of a global variable, declaring it when it doesn't exist
+    ///
+    /// The value is converted to the declared type of the variable, a int is accepted where a
+    /// float is expected
+    pub fn set_global(
+        &mut self,
+        name: &str,
+        value: bytecode::Value,
+    ) -> Result<(), handlers::error_handler::Error> {
+        let (slot, value) = match self.compiler.global_slot(name) {
+            Some((slot, r#type)) => {
+                let found = operations::type_name(&value);
+
+                match operations::convert(value, &r#type) {
+                    Some(value) => (slot, value),
+                    None => {
+                        return Err(engine_error(
+                            &format!("\"{}\" is {} but the value is {}.", name, r#type, found),
+                            handlers::error_handler::ErrorCode::E029,
+                        ))
+                    }
+                }
+            }
+            None => (self.compiler.declare_global(name), value),
+        };
+
+        self.runtime.set_global(slot, value);
+
+        Ok(())
+    }
+}
+
+/// Create a error of the engine, it has no position in a script
+fn engine_error(
+    content: &str,
+    code: handlers::error_handler::ErrorCode,
+) -> handlers::error_handler::Error {
+    handlers::error_handler::Error::Error(handlers::error_handler::BaseError::new(
+        handlers::message_handler::Message::new(content, handlers::message_handler::Issuer::Engine),
+        code,
+    ))
+}
+
+/// Read the source of a script file
+fn read_source(path: &Path) -> Result<String, handlers::error_handler::Error> {
+    std::fs::read_to_string(path).map_err(|err| {
+        engine_error(
+            &format!("could not read \"{}\", {}.", path.display(), err),
+            handlers::error_handler::ErrorCode::E018,
+        )
+    })
+}
@@ -0,0 +1,233 @@
+use crate::engine::Engine;
+use crate::library;
+use std::cmp::Ordering;
+use utils::backend::bytecode::Value;
+use utils::backend::operations;
+use utils::types::built_in_types::BuiltInTypes;
+
+/// Register the "std::array" functions
+///
+/// Arrays are values, so the functions that change a array return the changed array
+/// -> list = array::push(list, 4);
+pub fn register(engine: &mut Engine) {
+    engine.register_native(
+        "std::array::len",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Int,
+        |args| match args.as_slice() {
+            [Value::Array(values)] => library::length(values.len()),
+            _ => Err("expected a array".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::push",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values), value] => {
+                values.push(value.to_owned());
+                Ok(args.swap_remove(0))
+            }
+            _ => Err("expected a array and a value".to_string()),
+        },
+    );
+
+    // The array without its last value, the value is read with "last"
+    engine.register_native(
+        "std::array::pop",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values)] => match values.pop() {
+                Some(_) => Ok(args.swap_remove(0)),
+                None => Err("the array is empty".to_string()),
+            },
+            _ => Err("expected a array".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::first",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::Array(values)] => values
+                .first()
+                .cloned()
+                .ok_or_else(|| "the array is empty".to_string()),
+            _ => Err("expected a array".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::last",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::Array(values)] => values
+                .last()
+                .cloned()
+                .ok_or_else(|| "the array is empty".to_string()),
+            _ => Err("expected a array".to_string()),
+        },
+    );
+
+    // insert(array, index, value) <- the index can be the length to insert in the end
+    engine.register_native(
+        "std::array::insert",
+        vec![BuiltInTypes::Any, BuiltInTypes::Int, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values), Value::Int(index), value] => {
+                let (index, _) = library::range(*index, *index, values.len())?;
+
+                values.insert(index, value.to_owned());
+                Ok(args.swap_remove(0))
+            }
+            _ => Err("expected a array, a index and a value".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::remove",
+        vec![BuiltInTypes::Any, BuiltInTypes::Int],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values), Value::Int(index)] => {
+                let (index, _) = library::range(*index, *index + 1, values.len())?;
+
+                values.remove(index);
+                Ok(args.swap_remove(0))
+            }
+            _ => Err("expected a array and a index".to_string()),
+        },
+    );
+
+    // slice(array, start, end) <- the end is not included
+    engine.register_native(
+        "std::array::slice",
+        vec![BuiltInTypes::Any, BuiltInTypes::Int, BuiltInTypes::Int],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::Array(values), Value::Int(start), Value::Int(end)] => {
+                let (start, end) = library::range(*start, *end, values.len())?;
+
+                Ok(Value::Array(values[start..end].to_vec()))
+            }
+            _ => Err("expected a array, a start and a end".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::concat",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::Array(left), Value::Array(right)] => {
+                Ok(Value::Array([left.as_slice(), right.as_slice()].concat()))
+            }
+            _ => Err("expected two arrays".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::contains",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Bool,
+        |args| match args.as_slice() {
+            [Value::Array(values), value] => Ok(Value::Bool(
+                values
+                    .iter()
+                    .any(|element| operations::equals(element, value)),
+            )),
+            _ => Err("expected a array and a value".to_string()),
+        },
+    );
+
+    // The index of the first equal value, -1 when there is no one
+    engine.register_native(
+        "std::array::index_of",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Int,
+        |args| match args.as_slice() {
+            [Value::Array(values), value] => {
+                match values
+                    .iter()
+                    .position(|element| operations::equals(element, value))
+                {
+                    Some(index) => library::length(index),
+                    None => Ok(Value::Int(-1)),
+                }
+            }
+            _ => Err("expected a array and a value".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::array::reverse",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values)] => {
+                values.reverse();
+                Ok(args.swap_remove(0))
+            }
+            _ => Err("expected a array".to_string()),
+        },
+    );
+
+    // Sort in ascending order, every value must be comparable with the others like in "<"
+    engine.register_native(
+        "std::array::sort",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |mut args| match args.as_mut_slice() {
+            [Value::Array(values)] => {
+                check_sortable(values)?;
+                values.sort_by(total_order);
+
+                Ok(args.swap_remove(0))
+            }
+            _ => Err("expected a array".to_string()),
+        },
+    );
+}
+
+/// Check that the values can be sorted, a NaN or two values that can't be compared are a error
+fn check_sortable(values: &[Value]) -> Result<(), String> {
+    if values
+        .iter()
+        .any(|value| matches!(value, Value::Float(value) if value.is_nan()))
+    {
+        return Err("can't sort a array with NaN".to_string());
+    }
+
+    // Numbers, strs and chars are only comparable between them, checking the first is enough
+    match values.first() {
+        Some(first) => values.iter().try_for_each(|value| {
+            operations::compare(first, value)
+                .map(|_| ())
+                .map_err(|kind| format!("can't sort, {}", kind.to_string().trim_end_matches('.')))
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Order two values of a sortable array <- ints and floats are exact as f64
+fn total_order(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::String(left), Value::String(right)) => left.cmp(right),
+        (Value::Char(left), Value::Char(right)) => left.cmp(right),
+        (left, right) => as_f64(left).total_cmp(&as_f64(right)),
+    }
+}
+
+/// Read a int or a float as a f64, every int and float has the same value in it
+fn as_f64(value: &Value) -> f64 {
+    match value {
+        Value::Int(value) => f64::from(*value),
+        Value::Float(value) => f64::from(*value),
+        _ => f64::NAN,
+    }
+}
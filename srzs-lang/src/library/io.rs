@@ -0,0 +1,143 @@
+use crate::engine::Engine;
+use std::fmt;
+use std::fs;
+use std::io::{BufRead, Write};
+use utils::backend::bytecode::Value;
+use utils::types::built_in_types::BuiltInTypes;
+
+/// Where the functions of "std::io" write and read, the engine can replace them
+pub struct Streams {
+    pub output: Box<dyn Write>,
+    pub error: Box<dyn Write>,
+    pub input: Box<dyn BufRead>,
+}
+
+impl Default for Streams {
+    fn default() -> Self {
+        Self {
+            output: Box::new(std::io::stdout()),
+            error: Box::new(std::io::stderr()),
+            input: Box::new(std::io::BufReader::new(std::io::stdin())),
+        }
+    }
+}
+
+impl fmt::Debug for Streams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Streams").finish_non_exhaustive()
+    }
+}
+
+/// Register the "std::io" functions
+pub fn register(engine: &mut Engine) {
+    for (name, end, error) in [
+        ("std::io::print", "", false),
+        ("std::io::println", "\n", false),
+        ("std::io::eprint", "", true),
+        ("std::io::eprintln", "\n", true),
+    ] {
+        let streams = engine.streams();
+
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Any],
+            BuiltInTypes::Void,
+            move |args| {
+                let mut streams = streams.borrow_mut();
+                let stream = match error {
+                    true => &mut streams.error,
+                    false => &mut streams.output,
+                };
+
+                write!(stream, "{}{}", args[0], end)
+                    .and_then(|_| stream.flush())
+                    .map(|_| Value::Null)
+                    .map_err(|err| err.to_string())
+            },
+        );
+    }
+
+    // Read a line of the input without its line break, it's null in the end of the input
+    let streams = engine.streams();
+
+    engine.register_native("std::io::read_line", vec![], BuiltInTypes::Any, move |_| {
+        let mut line = String::new();
+
+        let read = streams
+            .borrow_mut()
+            .input
+            .read_line(&mut line)
+            .map_err(|err| err.to_string())?;
+
+        if read == 0 {
+            return Ok(Value::Null);
+        }
+
+        let end = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(end);
+
+        Ok(Value::String(line))
+    });
+
+    engine.register_native(
+        "std::io::read_file",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Str,
+        |args| match args.as_slice() {
+            [Value::String(path)] => fs::read_to_string(path)
+                .map(Value::String)
+                .map_err(|err| format!("could not read \"{}\", {}", path, err)),
+            _ => Err("expected a path".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::io::write_file",
+        vec![BuiltInTypes::Str, BuiltInTypes::Str],
+        BuiltInTypes::Void,
+        |args| match args.as_slice() {
+            [Value::String(path), Value::String(content)] => fs::write(path, content)
+                .map(|_| Value::Null)
+                .map_err(|err| format!("could not write \"{}\", {}", path, err)),
+            _ => Err("expected a path and a content".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::io::append_file",
+        vec![BuiltInTypes::Str, BuiltInTypes::Str],
+        BuiltInTypes::Void,
+        |args| match args.as_slice() {
+            [Value::String(path), Value::String(content)] => fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(path)
+                .and_then(|mut file| file.write_all(content.as_bytes()))
+                .map(|_| Value::Null)
+                .map_err(|err| format!("could not write \"{}\", {}", path, err)),
+            _ => Err("expected a path and a content".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::io::remove_file",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Void,
+        |args| match args.as_slice() {
+            [Value::String(path)] => fs::remove_file(path)
+                .map(|_| Value::Null)
+                .map_err(|err| format!("could not remove \"{}\", {}", path, err)),
+            _ => Err("expected a path".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::io::file_exists",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Bool,
+        |args| match args.as_slice() {
+            [Value::String(path)] => Ok(Value::Bool(std::path::Path::new(path).exists())),
+            _ => Err("expected a path".to_string()),
+        },
+    );
+}
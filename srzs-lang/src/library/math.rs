@@ -0,0 +1,236 @@
+use crate::engine::Engine;
+use utils::backend::bytecode::Value;
+use utils::types::built_in_types::BuiltInTypes;
+
+/// A numeric argument, ints stay ints until a float is mixed with them like in arithmetic
+#[derive(Clone, Copy)]
+enum Number {
+    Int(i32),
+    Float(f32),
+}
+
+impl Number {
+    fn as_float(self) -> f32 {
+        match self {
+            Number::Int(value) => value as f32,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+impl From<Number> for Value {
+    fn from(number: Number) -> Self {
+        match number {
+            Number::Int(value) => Value::Int(value),
+            Number::Float(value) => Value::Float(value),
+        }
+    }
+}
+
+/// Take the numeric arguments of a call, any other value is a error
+fn numbers(args: &[Value]) -> Result<Vec<Number>, String> {
+    args.iter()
+        .map(|arg| match arg {
+            Value::Int(value) => Ok(Number::Int(*value)),
+            Value::Float(value) => Ok(Number::Float(*value)),
+            _ => Err("expected a int or a float".to_string()),
+        })
+        .collect()
+}
+
+/// Apply a int operation when every number is a int, and the float one otherwise
+fn promote(
+    numbers: &[Number],
+    int: impl Fn(&[i32]) -> Option<i32>,
+    float: impl Fn(&[f32]) -> f32,
+) -> Result<Value, String> {
+    let ints: Option<Vec<i32>> = numbers
+        .iter()
+        .map(|number| match number {
+            Number::Int(value) => Some(*value),
+            Number::Float(_) => None,
+        })
+        .collect();
+
+    match ints {
+        Some(ints) => int(&ints)
+            .map(Value::Int)
+            .ok_or_else(|| "integer overflow".to_string()),
+        None => Ok(Value::Float(float(
+            &numbers
+                .iter()
+                .map(|number| number.as_float())
+                .collect::<Vec<f32>>(),
+        ))),
+    }
+}
+
+/// Register the "std::math" functions and constants
+pub fn register(engine: &mut Engine) {
+    for (name, value) in [
+        ("std::math::PI", Value::Float(std::f32::consts::PI)),
+        ("std::math::TAU", Value::Float(std::f32::consts::TAU)),
+        ("std::math::E", Value::Float(std::f32::consts::E)),
+        ("std::math::INFINITY", Value::Float(f32::INFINITY)),
+        ("std::math::INT_MAX", Value::Int(i32::MAX)),
+        ("std::math::INT_MIN", Value::Int(i32::MIN)),
+    ] {
+        engine.register_constant(name, value);
+    }
+
+    // A int stays a int -> abs(-2) = 2, abs(-2.5) = 2.5
+    engine.register_native(
+        "std::math::abs",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| {
+            promote(
+                &numbers(&args)?,
+                |ints| ints[0].checked_abs(),
+                |floats| floats[0].abs(),
+            )
+        },
+    );
+
+    engine.register_native(
+        "std::math::min",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| {
+            promote(
+                &numbers(&args)?,
+                |ints| Some(ints[0].min(ints[1])),
+                |floats| floats[0].min(floats[1]),
+            )
+        },
+    );
+
+    engine.register_native(
+        "std::math::max",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| {
+            promote(
+                &numbers(&args)?,
+                |ints| Some(ints[0].max(ints[1])),
+                |floats| floats[0].max(floats[1]),
+            )
+        },
+    );
+
+    // clamp(value, min, max) <- the min can't be greater than the max
+    engine.register_native(
+        "std::math::clamp",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| {
+            let numbers = numbers(&args)?;
+
+            if numbers[1].as_float() > numbers[2].as_float() {
+                return Err("the min is greater than the max".to_string());
+            }
+
+            promote(
+                &numbers,
+                |ints| Some(ints[0].clamp(ints[1], ints[2])),
+                |floats| floats[0].clamp(floats[1], floats[2]),
+            )
+        },
+    );
+
+    // A int raised to a negative int is a float -> pow(2, -1) = 0.5
+    engine.register_native(
+        "std::math::pow",
+        vec![BuiltInTypes::Any, BuiltInTypes::Any],
+        BuiltInTypes::Any,
+        |args| match numbers(&args)?.as_slice() {
+            [Number::Int(base), Number::Int(exponent)] if *exponent >= 0 => base
+                .checked_pow(*exponent as u32)
+                .map(Value::Int)
+                .ok_or_else(|| "integer overflow".to_string()),
+            [base, exponent] => Ok(Value::Float(base.as_float().powf(exponent.as_float()))),
+            _ => Err("expected a base and a exponent".to_string()),
+        },
+    );
+
+    // Rounding a int gives the same int
+    for (name, function) in [
+        ("std::math::floor", f32::floor as fn(f32) -> f32),
+        ("std::math::ceil", f32::ceil),
+        ("std::math::round", f32::round),
+        ("std::math::trunc", f32::trunc),
+    ] {
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Any],
+            BuiltInTypes::Any,
+            move |args| {
+                promote(
+                    &numbers(&args)?,
+                    |ints| Some(ints[0]),
+                    |floats| function(floats[0]),
+                )
+            },
+        );
+    }
+
+    // The result is always a float
+    for (name, function) in [
+        ("std::math::sqrt", f32::sqrt as fn(f32) -> f32),
+        ("std::math::exp", f32::exp),
+        ("std::math::ln", f32::ln),
+        ("std::math::log10", f32::log10),
+        ("std::math::sin", f32::sin),
+        ("std::math::cos", f32::cos),
+        ("std::math::tan", f32::tan),
+        ("std::math::asin", f32::asin),
+        ("std::math::acos", f32::acos),
+        ("std::math::atan", f32::atan),
+    ] {
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Float],
+            BuiltInTypes::Float,
+            move |args| Ok(Value::Float(function(numbers(&args)?[0].as_float()))),
+        );
+    }
+
+    for (name, function) in [
+        ("std::math::log", f32::log as fn(f32, f32) -> f32),
+        ("std::math::atan2", f32::atan2),
+    ] {
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Float, BuiltInTypes::Float],
+            BuiltInTypes::Float,
+            move |args| {
+                let numbers = numbers(&args)?;
+                Ok(Value::Float(function(
+                    numbers[0].as_float(),
+                    numbers[1].as_float(),
+                )))
+            },
+        );
+    }
+
+    engine.register_native(
+        "std::math::to_float",
+        vec![BuiltInTypes::Float],
+        BuiltInTypes::Float,
+        |args| Ok(Number::Float(numbers(&args)?[0].as_float()).into()),
+    );
+
+    // The decimal part is dropped -> to_int(2.7) = 2
+    engine.register_native(
+        "std::math::to_int",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Int,
+        |args| match numbers(&args)?[0] {
+            Number::Int(value) => Ok(Value::Int(value)),
+            Number::Float(value) if value.is_finite() && value.trunc().abs() < 2147483648.0 => {
+                Ok(Value::Int(value as i32))
+            }
+            Number::Float(value) => Err(format!("{:?} can't be a int", value)),
+        },
+    );
+}
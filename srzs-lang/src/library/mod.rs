@@ -0,0 +1,32 @@
+use crate::engine::Engine;
+use utils::backend::bytecode::Value;
+pub mod array;
+pub mod io;
+pub mod math;
+pub mod string;
+
+/// Register every module of the standard library in a engine
+pub fn register(engine: &mut Engine) {
+    io::register(engine);
+    math::register(engine);
+    string::register(engine);
+    array::register(engine);
+}
+
+/// A length or a index as a int value
+fn length(length: usize) -> Result<Value, String> {
+    i32::try_from(length)
+        .map(Value::Int)
+        .map_err(|_| "the length is too big for a int".to_string())
+}
+
+/// Check a range of indexes for a length -> (start, end)
+fn range(start: i32, end: i32, length: usize) -> Result<(usize, usize), String> {
+    match (usize::try_from(start), usize::try_from(end)) {
+        (Ok(first), Ok(last)) if first <= last && last <= length => Ok((first, last)),
+        _ => Err(format!(
+            "the range {}..{} is out of bounds for length {}",
+            start, end, length
+        )),
+    }
+}
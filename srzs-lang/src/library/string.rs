@@ -0,0 +1,200 @@
+use crate::engine::Engine;
+use crate::library;
+use utils::backend::bytecode::Value;
+use utils::types::built_in_types::BuiltInTypes;
+
+/// Register the "std::string" functions, indexes count chars and not bytes
+pub fn register(engine: &mut Engine) {
+    engine.register_native(
+        "std::string::len",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Int,
+        |args| match args.as_slice() {
+            [Value::String(string)] => library::length(string.chars().count()),
+            _ => Err("expected a str".to_string()),
+        },
+    );
+
+    // slice(string, start, end) <- the end is not included
+    engine.register_native(
+        "std::string::slice",
+        vec![BuiltInTypes::Str, BuiltInTypes::Int, BuiltInTypes::Int],
+        BuiltInTypes::Str,
+        |args| match args.as_slice() {
+            [Value::String(string), Value::Int(start), Value::Int(end)] => {
+                let (start, end) = library::range(*start, *end, string.chars().count())?;
+
+                Ok(Value::String(
+                    string.chars().skip(start).take(end - start).collect(),
+                ))
+            }
+            _ => Err("expected a str, a start and a end".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::string::split",
+        vec![BuiltInTypes::Str, BuiltInTypes::Str],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::String(string), Value::String(separator)] => Ok(Value::Array(
+                string.split(separator.as_str()).map(Value::from).collect(),
+            )),
+            _ => Err("expected a str and a separator".to_string()),
+        },
+    );
+
+    // Every element of the array is converted to a str
+    engine.register_native(
+        "std::string::join",
+        vec![BuiltInTypes::Any, BuiltInTypes::Str],
+        BuiltInTypes::Str,
+        |args| match args.as_slice() {
+            [Value::Array(values), Value::String(separator)] => Ok(Value::String(
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(separator),
+            )),
+            _ => Err("expected a array and a separator".to_string()),
+        },
+    );
+
+    // The char index of the first match, -1 when there is no match
+    engine.register_native(
+        "std::string::find",
+        vec![BuiltInTypes::Str, BuiltInTypes::Str],
+        BuiltInTypes::Int,
+        |args| match args.as_slice() {
+            [Value::String(string), Value::String(pattern)] => {
+                match string.find(pattern.as_str()) {
+                    Some(index) => library::length(string[..index].chars().count()),
+                    None => Ok(Value::Int(-1)),
+                }
+            }
+            _ => Err("expected a str and a pattern".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::string::replace",
+        vec![BuiltInTypes::Str, BuiltInTypes::Str, BuiltInTypes::Str],
+        BuiltInTypes::Str,
+        |args| match args.as_slice() {
+            [Value::String(string), Value::String(from), Value::String(to)] => {
+                Ok(Value::String(string.replace(from.as_str(), to)))
+            }
+            _ => Err("expected a str, a pattern and a replacement".to_string()),
+        },
+    );
+
+    for (name, function) in [
+        ("std::string::contains", |string: &str, pattern: &str| {
+            string.contains(pattern)
+        }),
+        ("std::string::starts_with", |string: &str, pattern: &str| {
+            string.starts_with(pattern)
+        }),
+        ("std::string::ends_with", |string: &str, pattern: &str| {
+            string.ends_with(pattern)
+        }),
+    ] as [(&str, fn(&str, &str) -> bool); 3]
+    {
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Str, BuiltInTypes::Str],
+            BuiltInTypes::Bool,
+            move |args| match args.as_slice() {
+                [Value::String(string), Value::String(pattern)] => {
+                    Ok(Value::Bool(function(string, pattern)))
+                }
+                _ => Err("expected a str and a pattern".to_string()),
+            },
+        );
+    }
+
+    for (name, function) in [
+        ("std::string::trim", |string: &str| {
+            string.trim().to_string()
+        }),
+        ("std::string::trim_start", |string: &str| {
+            string.trim_start().to_string()
+        }),
+        ("std::string::trim_end", |string: &str| {
+            string.trim_end().to_string()
+        }),
+        ("std::string::upper", str::to_uppercase),
+        ("std::string::lower", str::to_lowercase),
+    ] as [(&str, fn(&str) -> String); 5]
+    {
+        engine.register_native(
+            name,
+            vec![BuiltInTypes::Str],
+            BuiltInTypes::Str,
+            move |args| match args.as_slice() {
+                [Value::String(string)] => Ok(Value::String(function(string))),
+                _ => Err("expected a str".to_string()),
+            },
+        );
+    }
+
+    // A array with every char of the str, used to iterate over it
+    engine.register_native(
+        "std::string::chars",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Any,
+        |args| match args.as_slice() {
+            [Value::String(string)] => Ok(Value::Array(string.chars().map(Value::Char).collect())),
+            _ => Err("expected a str".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::string::repeat",
+        vec![BuiltInTypes::Str, BuiltInTypes::Int],
+        BuiltInTypes::Str,
+        |args| match args.as_slice() {
+            [Value::String(string), Value::Int(count)] => match usize::try_from(*count) {
+                Ok(count) => Ok(Value::String(string.repeat(count))),
+                Err(_) => Err(format!("can't repeat {} times", count)),
+            },
+            _ => Err("expected a str and a count".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::string::to_string",
+        vec![BuiltInTypes::Any],
+        BuiltInTypes::Str,
+        |args| Ok(Value::String(args[0].to_string())),
+    );
+
+    engine.register_native(
+        "std::string::parse_int",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Int,
+        |args| match args.as_slice() {
+            [Value::String(string)] => string
+                .trim()
+                .parse::<i32>()
+                .map(Value::Int)
+                .map_err(|_| format!("\"{}\" is not a int", string)),
+            _ => Err("expected a str".to_string()),
+        },
+    );
+
+    engine.register_native(
+        "std::string::parse_float",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Float,
+        |args| match args.as_slice() {
+            [Value::String(string)] => string
+                .trim()
+                .parse::<f32>()
+                .map(Value::Float)
+                .map_err(|_| format!("\"{}\" is not a float", string)),
+            _ => Err("expected a str".to_string()),
+        },
+    );
+}
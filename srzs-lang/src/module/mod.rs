@@ -1,6 +1,16 @@
 use utils::backend::bytecode;
 
+/// A compiled module, its items are named with its path -> module::item
 pub struct Module {
-    name: String,
-    bytecode: bytecode::ByteCode,
+    pub name: String,
+    pub bytecode: bytecode::ByteCode,
+}
+
+impl Module {
+    pub fn new(name: &str, bytecode: bytecode::ByteCode) -> Self {
+        Self {
+            name: name.to_string(),
+            bytecode,
+        }
+    }
 }
@@ -1,92 +1,853 @@
-use crate::module::Module;
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
 use utils::backend::bytecode;
+use utils::backend::operations;
+use utils::handlers::error_handler;
+use utils::types::built_in_types::BuiltInTypes;
 
+/// The most function calls that can run at the same time, a deeper call is a stack overflow
+pub const MAX_CALL_DEPTH: usize = 1024;
+
+/// A host function, it takes the arguments of the call and returns its value or a error message
+pub type NativeFunction = Rc<dyn Fn(Vec<bytecode::Value>) -> Result<bytecode::Value, String>>;
+
+/// A function registered by the host, its index in the native list is the native id
+#[derive(Clone)]
+struct Native {
+    name: String,
+    params: Vec<BuiltInTypes>,
+    returns: BuiltInTypes,
+    function: NativeFunction,
+}
+
+impl fmt::Debug for Native {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Native")
+            .field("name", &self.name)
+            .field("params", &self.params)
+            .field("returns", &self.returns)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A function loaded in the runtime, its index in the function list is the function id
 #[derive(Debug, Clone)]
-enum Stack {
-    Global(Vec<bytecode::Value>),
-    Local((Vec<bytecode::Value>, Vec<bytecode::Value>)),
+struct Function {
+    name: String,
+    params: usize,
+    code: Vec<bytecode::ByteNode>,
+}
+
+/// A struct loaded in the runtime, its index in the struct list is the struct id
+#[derive(Debug, Clone)]
+struct Struct {
+    name: String,
+    fields: Vec<(String, BuiltInTypes)>,
+}
+
+/// The state of a function call <- each call has its own arguments and locals
+#[derive(Debug, Clone)]
+struct Frame {
+    function: usize,
+    /// Index of the next instruction of the function
+    pointer: usize,
+    args: Vec<bytecode::Value>,
+    /// The locals in the bottom and the values of the expression being evaluated in the top
+    stack: Vec<bytecode::Value>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Runtime {
-    global_stack: Stack,
-    local_stack: Stack,
-    stack_memory: Vec<Stack>,
+    global_stack: Vec<bytecode::Value>,
+    /// Frames of the functions being called, the last one is the running function
+    stack_memory: Vec<Frame>,
+    functions: Vec<Function>,
+    natives: Vec<Native>,
+    structs: Vec<Struct>,
 }
 
 impl Runtime {
     pub fn new() -> Self {
         Self {
-            global_stack: Stack::Global(vec![]),
-            local_stack: Stack::Local((vec![], vec![])),
+            global_stack: vec![],
             stack_memory: vec![],
+            functions: vec![],
+            natives: vec![],
+            structs: vec![],
         }
     }
 
-    pub fn run_byte(&mut self, input: bytecode::ByteCode) {
-        for scope in input.code {
+    /// Register a host function, its native id is the order of registration
+    pub fn register_native(
+        &mut self,
+        name: &str,
+        params: Vec<BuiltInTypes>,
+        returns: BuiltInTypes,
+        function: NativeFunction,
+    ) {
+        self.natives.push(Native {
+            name: name.to_string(),
+            params,
+            returns,
+            function,
+        });
+    }
+
+    /// Return the stack where the current code pushes and pops values
+    fn stack(&mut self) -> &mut Vec<bytecode::Value> {
+        match self.stack_memory.last_mut() {
+            Some(frame) => &mut frame.stack,
+            None => &mut self.global_stack,
+        }
+    }
+
+    /// Return the value scope of the current stack, the global code uses the global stack
+    fn stack_scope(&self) -> bytecode::ValueScope {
+        match self.stack_memory.is_empty() {
+            true => bytecode::ValueScope::Global,
+            false => bytecode::ValueScope::Local,
+        }
+    }
+
+    /// Return the stack of a value scope <- the args of the running function are a stack too
+    fn scope_stack(&mut self, scope: &bytecode::ValueScope) -> &mut Vec<bytecode::Value> {
+        match (scope, self.stack_memory.last_mut()) {
+            (bytecode::ValueScope::Local, Some(frame)) => &mut frame.stack,
+            (bytecode::ValueScope::Args, Some(frame)) => &mut frame.args,
+            _ => &mut self.global_stack,
+        }
+    }
+
+    /// Pop the value in the top of the current stack
+    fn pop(&mut self) -> Result<bytecode::Value, error_handler::RuntimeErrorKind> {
+        self.stack()
+            .pop()
+            .ok_or(error_handler::RuntimeErrorKind::StackUnderflow)
+    }
+
+    /// Pop the two operands of a binary operation <- the right one is in the top
+    fn pop_operands(
+        &mut self,
+    ) -> Result<(bytecode::Value, bytecode::Value), error_handler::RuntimeErrorKind> {
+        let right = self.pop()?;
+        let left = self.pop()?;
+
+        Ok((left, right))
+    }
+
+    /// Pop two values and push the result of a arithmetic operation
+    fn arithmetic(
+        &mut self,
+        operator: operations::Arithmetic,
+    ) -> Result<(), error_handler::RuntimeErrorKind> {
+        let (left, right) = self.pop_operands()?;
+        let result = operations::arithmetic(operator, &left, &right)?;
+
+        self.stack().push(result);
+
+        Ok(())
+    }
+
+    /// Pop two values, compare them and push true if the ordering is one of the expected
+    fn compare(&mut self, expected: &[Ordering]) -> Result<(), error_handler::RuntimeErrorKind> {
+        let (left, right) = self.pop_operands()?;
+
+        let result = match operations::compare(&left, &right)? {
+            Some(ordering) => expected.contains(&ordering),
+            None => false,
+        };
+
+        self.stack().push(bytecode::Value::Bool(result));
+
+        Ok(())
+    }
+
+    /// Fetch the next instruction of the running function, or of the code of this run
+    fn fetch(
+        &mut self,
+        base: usize,
+        code: &[bytecode::ByteNode],
+        pointer: &mut usize,
+    ) -> Option<(bytecode::ByteNode, error_handler::BytecodeLocation)> {
+        // The frames below the base belong to who is running this code
+        if self.stack_memory.len() > base {
+            let frame = self.stack_memory.last_mut()?;
+            let function = &self.functions[frame.function];
+            let location = error_handler::BytecodeLocation {
+                function: Some(function.name.to_owned()),
+                instruction: frame.pointer,
+            };
+
+            // A function without a return in the end returns the value in the top of its stack
+            let statement = function
+                .code
+                .get(frame.pointer)
+                .cloned()
+                .unwrap_or(bytecode::ByteNode::Ret);
+            frame.pointer += 1;
+
+            return Some((statement, location));
+        }
+
+        let statement = code.get(*pointer).cloned()?;
+        let location = error_handler::BytecodeLocation {
+            function: None,
+            instruction: *pointer,
+        };
+        *pointer += 1;
+
+        Some((statement, location))
+    }
+
+    /// Move the instruction pointer of the running function, or of the code of this run
+    fn jump(&mut self, base: usize, pointer: &mut usize, target: usize) {
+        let running_function = self.stack_memory.len() > base;
+
+        match self.stack_memory.last_mut() {
+            Some(frame) if running_function => frame.pointer = target,
+            _ => *pointer = target,
+        }
+    }
+
+    /// Return the value of a global slot
+    pub fn global(&self, slot: usize) -> Option<&bytecode::Value> {
+        self.global_stack.get(slot)
+    }
+
+    /// Replace the value of a global slot, or push it if the slot is the top of the global stack
+    pub fn set_global(&mut self, slot: usize, value: bytecode::Value) {
+        match self.global_stack.get_mut(slot) {
+            Some(global) => *global = value,
+            None => self.global_stack.push(value),
+        }
+    }
+
+    /// The values of the globals and the number of functions and structs, used to undo a failed run
+    pub fn checkpoint(&self) -> (Vec<bytecode::Value>, usize, usize) {
+        (
+            self.global_stack.to_owned(),
+            self.functions.len(),
+            self.structs.len(),
+        )
+    }
+
+    /// Restore the globals and drop the functions and structs added after a checkpoint
+    pub fn rollback(&mut self, checkpoint: (Vec<bytecode::Value>, usize, usize)) {
+        self.global_stack = checkpoint.0;
+        self.functions.truncate(checkpoint.1);
+        self.structs.truncate(checkpoint.2);
+    }
+
+    /// Check a value given to a struct field against the declared type of the field
+    fn field_value(
+        declaration: &Struct,
+        field: &str,
+        value: bytecode::Value,
+    ) -> Result<bytecode::Value, error_handler::RuntimeErrorKind> {
+        let r#type = match declaration.fields.iter().find(|(name, _)| name == field) {
+            Some((_, r#type)) => r#type,
+            None => {
+                return Err(error_handler::RuntimeErrorKind::UnknownField(
+                    declaration.name.to_owned(),
+                    field.to_string(),
+                ))
+            }
+        };
+        let found = operations::type_name(&value);
+
+        operations::convert(value, r#type).ok_or(error_handler::RuntimeErrorKind::TypeMismatch(
+            format!(
+                "field \"{}\" of \"{}\" must be {} but it's {}",
+                field, declaration.name, r#type, found
+            ),
+        ))
+    }
+
+    /// Call a function by its id and return its value
+    pub fn call(
+        &mut self,
+        function: usize,
+        args: Vec<bytecode::Value>,
+    ) -> Result<bytecode::Value, error_handler::Error> {
+        let base = self.stack_memory.len();
+        // The call starts in the first instruction of the function
+        let location = error_handler::BytecodeLocation {
+            function: self
+                .functions
+                .get(function)
+                .map(|item| item.name.to_owned()),
+            instruction: 0,
+        };
+
+        // The arguments are pushed like a call instruction would do
+        let argc = args.len();
+        self.stack().extend(args);
+
+        if let Err(kind) = self.execute(bytecode::ByteNode::Call((function, argc)), base, &mut 0) {
+            let stack = self.stack();
+            stack.truncate(stack.len() - argc);
+
+            return Err(error_handler::Error::RuntimeError(
+                error_handler::RuntimeError::new(kind, location),
+            ));
+        }
+
+        // Without global code, it runs until the function returns
+        self.run_frames(&[], base)?;
+
+        self.pop().map_err(|kind| {
+            error_handler::Error::RuntimeError(error_handler::RuntimeError::new(kind, location))
+        })
+    }
+
+    pub fn run_byte(&mut self, input: bytecode::ByteCode) -> Result<(), error_handler::Error> {
+        // Load the functions and structs first, the global code can use them before their declaration
+        for scope in input.code.iter() {
             match scope {
-                bytecode::Scope::Global(code) => {
-                    for statement in code {
-                        match statement {
-                            bytecode::ByteNode::Push(value) => {
-                                match &mut self.global_stack {
-                                    Stack::Global(stack) => {
-                                        stack.insert(stack.len(), value);
-                                    }
-                                    Stack::Local(_) => {}
-                                };
-                            }
-                            bytecode::ByteNode::Pull(index) => {
-                                match &mut self.global_stack {
-                                    Stack::Global(stack) => {
-                                        let value = stack.get(index.1).unwrap().to_owned();
-
-                                        stack.insert(stack.len(), value);
-                                    }
-                                    Stack::Local(_) => {}
-                                };
-                            }
-                            bytecode::ByteNode::Pop(index) => {
-                                match &mut self.global_stack {
-                                    Stack::Global(stack) => {
-                                        stack.remove(index);
-                                    }
-                                    Stack::Local(_) => {}
-                                };
-                            }
-                            bytecode::ByteNode::Mov(index) => {
-                                match &mut self.global_stack {
-                                    Stack::Global(stack) => {
-                                        let value = stack.get(index).unwrap().to_owned();
-                                        stack.remove(index);
-
-                                        stack.insert(stack.len(), value);
-                                    }
-                                    Stack::Local(_) => {}
-                                };
-                            }
-                            bytecode::ByteNode::Add => todo!(),
-                            bytecode::ByteNode::Sub => todo!(),
-                            bytecode::ByteNode::Mul => todo!(),
-                            bytecode::ByteNode::Div => todo!(),
+                bytecode::Scope::Function { name, params, code } => {
+                    self.functions.push(Function {
+                        name: name.to_owned(),
+                        params: *params,
+                        code: code.to_owned(),
+                    });
+                }
+                bytecode::Scope::Struct { name, fields } => {
+                    self.structs.push(Struct {
+                        name: name.to_owned(),
+                        fields: fields.to_owned(),
+                    });
+                }
+                bytecode::Scope::Global(_) => {}
+            }
+        }
+
+        for scope in input.code {
+            if let bytecode::Scope::Global(code) = scope {
+                self.run_code(&code)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the instructions of a global scope, and of the functions it calls, until the last one
+    fn run_code(&mut self, code: &[bytecode::ByteNode]) -> Result<(), error_handler::Error> {
+        // Frames that already exist belong to who is running this code
+        let base = self.stack_memory.len();
+
+        self.run_frames(code, base)
+    }
+
+    /// Run the frames above the base until they return, then the global code until the last instruction
+    fn run_frames(
+        &mut self,
+        code: &[bytecode::ByteNode],
+        base: usize,
+    ) -> Result<(), error_handler::Error> {
+        // Index of the next instruction of the global code
+        let mut pointer: usize = 0;
+
+        while let Some((statement, location)) = self.fetch(base, code, &mut pointer) {
+            if let Err(kind) = self.execute(statement, base, &mut pointer) {
+                // Drop the frames of the failed calls
+                self.stack_memory.truncate(base);
+
+                return Err(error_handler::Error::RuntimeError(
+                    error_handler::RuntimeError::new(kind, location),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Execute a single instruction
+    fn execute(
+        &mut self,
+        statement: bytecode::ByteNode,
+        base: usize,
+        pointer: &mut usize,
+    ) -> Result<(), error_handler::RuntimeErrorKind> {
+        match statement {
+            bytecode::ByteNode::Push(value) => {
+                self.stack().push(value);
+            }
+            bytecode::ByteNode::Pull((scope, index)) => {
+                let value = match self.scope_stack(&scope).get(index) {
+                    Some(value) => value.to_owned(),
+                    None => return Err(error_handler::RuntimeErrorKind::BadIndex(scope, index)),
+                };
+
+                self.stack().push(value);
+            }
+            bytecode::ByteNode::Store((scope, index)) => {
+                let value = self.pop()?;
+
+                match self.scope_stack(&scope).get_mut(index) {
+                    Some(slot) => *slot = value,
+                    None => return Err(error_handler::RuntimeErrorKind::BadIndex(scope, index)),
+                }
+            }
+            bytecode::ByteNode::Pop(index) | bytecode::ByteNode::Mov(index) => {
+                let scope = self.stack_scope();
+                let stack = self.stack();
+
+                if index >= stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::BadIndex(scope, index));
+                }
+
+                let value = stack.remove(index);
+
+                // Mov puts the removed value in the top
+                if let bytecode::ByteNode::Mov(_) = statement {
+                    stack.push(value);
+                }
+            }
+            bytecode::ByteNode::MakeArray(length) => {
+                let stack = self.stack();
+
+                if length > stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::StackUnderflow);
+                }
+
+                let values = stack.split_off(stack.len() - length);
+                stack.push(bytecode::Value::Array(values));
+            }
+            bytecode::ByteNode::Index => {
+                let (value, index) = self.pop_operands()?;
+                let element = operations::index(&value, &index)?;
+
+                self.stack().push(element);
+            }
+            bytecode::ByteNode::Convert(r#type) => {
+                let value = self.pop()?;
+                let found = operations::type_name(&value);
+
+                match operations::convert(value, &r#type) {
+                    Some(value) => self.stack().push(value),
+                    None => {
+                        return Err(error_handler::RuntimeErrorKind::TypeMismatch(format!(
+                            "expected {} but it's {}",
+                            r#type, found
+                        )))
+                    }
+                }
+            }
+            bytecode::ByteNode::MakeStruct(id) => {
+                let declaration = match self.structs.get(id) {
+                    Some(declaration) => declaration.to_owned(),
+                    None => return Err(error_handler::RuntimeErrorKind::UnknownStruct(id)),
+                };
+
+                let stack = self.stack();
+                let length = declaration.fields.len();
+
+                if length > stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::StackUnderflow);
+                }
+
+                let mut fields = vec![];
+
+                for ((field, _), value) in declaration
+                    .fields
+                    .iter()
+                    .zip(stack.split_off(stack.len() - length))
+                {
+                    fields.push((
+                        field.to_owned(),
+                        Runtime::field_value(&declaration, field, value)?,
+                    ));
+                }
+
+                self.stack().push(bytecode::Value::Struct {
+                    name: declaration.name,
+                    fields,
+                });
+            }
+            bytecode::ByteNode::GetField(field) => {
+                let value = self.pop()?;
+                let value = operations::field(&value, &field)?;
+
+                self.stack().push(value);
+            }
+            bytecode::ByteNode::SetField(field) => {
+                let (object, value) = self.pop_operands()?;
+
+                let (name, mut fields) = match object {
+                    bytecode::Value::Struct { name, fields } => (name, fields),
+                    object => {
+                        return Err(error_handler::RuntimeErrorKind::TypeMismatch(format!(
+                            "{} has no fields",
+                            operations::type_name(&object)
+                        )))
+                    }
+                };
+
+                // The declaration of the value is the last loaded struct with its name
+                let value = match self.structs.iter().rev().find(|item| item.name == name) {
+                    Some(declaration) => Runtime::field_value(declaration, &field, value)?,
+                    None => value,
+                };
+
+                match fields.iter_mut().find(|(name, _)| *name == field) {
+                    Some((_, slot)) => *slot = value,
+                    None => return Err(error_handler::RuntimeErrorKind::UnknownField(name, field)),
+                }
+
+                self.stack().push(bytecode::Value::Struct { name, fields });
+            }
+            bytecode::ByteNode::Jump(target) => self.jump(base, pointer, target),
+            bytecode::ByteNode::JumpIfFalse(target) => {
+                let condition = self.pop()?;
+
+                if !operations::is_truthy(&condition) {
+                    self.jump(base, pointer, target);
+                }
+            }
+            bytecode::ByteNode::Add => self.arithmetic(operations::Arithmetic::Add)?,
+            bytecode::ByteNode::Sub => self.arithmetic(operations::Arithmetic::Sub)?,
+            bytecode::ByteNode::Mul => self.arithmetic(operations::Arithmetic::Mul)?,
+            bytecode::ByteNode::Div => self.arithmetic(operations::Arithmetic::Div)?,
+            bytecode::ByteNode::Mod => self.arithmetic(operations::Arithmetic::Mod)?,
+            bytecode::ByteNode::Neg => {
+                let value = self.pop()?;
+                let result = operations::negate(&value)?;
+
+                self.stack().push(result);
+            }
+            bytecode::ByteNode::Eq => {
+                let (left, right) = self.pop_operands()?;
+
+                self.stack()
+                    .push(bytecode::Value::Bool(operations::equals(&left, &right)));
+            }
+            bytecode::ByteNode::Ne => {
+                let (left, right) = self.pop_operands()?;
+
+                self.stack()
+                    .push(bytecode::Value::Bool(!operations::equals(&left, &right)));
+            }
+            bytecode::ByteNode::Lt => self.compare(&[Ordering::Less])?,
+            bytecode::ByteNode::Gt => self.compare(&[Ordering::Greater])?,
+            bytecode::ByteNode::Le => self.compare(&[Ordering::Less, Ordering::Equal])?,
+            bytecode::ByteNode::Ge => self.compare(&[Ordering::Greater, Ordering::Equal])?,
+            bytecode::ByteNode::Not => {
+                let value = self.pop()?;
+
+                self.stack()
+                    .push(bytecode::Value::Bool(!operations::is_truthy(&value)));
+            }
+            bytecode::ByteNode::Call((function, argc)) => {
+                let (name, params) = match self.functions.get(function) {
+                    Some(function) => (function.name.to_owned(), function.params),
+                    None => return Err(error_handler::RuntimeErrorKind::UnknownFunction(function)),
+                };
+
+                if params != argc {
+                    return Err(error_handler::RuntimeErrorKind::ArityMismatch(format!(
+                        "function \"{}\" takes {} arguments but {} were given",
+                        name, params, argc
+                    )));
+                }
+
+                if self.stack_memory.len() >= MAX_CALL_DEPTH {
+                    return Err(error_handler::RuntimeErrorKind::StackOverflow(name));
+                }
+
+                // The arguments are the values in the top of the caller stack, in order
+                let stack = self.stack();
+
+                if argc > stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::StackUnderflow);
+                }
+
+                let args = stack.split_off(stack.len() - argc);
+
+                self.stack_memory.push(Frame {
+                    function,
+                    pointer: 0,
+                    args,
+                    stack: vec![],
+                });
+            }
+            bytecode::ByteNode::CallMethod((method, argc)) => {
+                let stack = self.stack();
+
+                if argc == 0 || argc > stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::StackUnderflow);
+                }
+
+                // The value is the deepest argument
+                let name = match &stack[stack.len() - argc] {
+                    bytecode::Value::Struct { name, .. } => name.to_owned(),
+                    value => {
+                        return Err(error_handler::RuntimeErrorKind::TypeMismatch(format!(
+                            "{} has no methods",
+                            operations::type_name(value)
+                        )))
+                    }
+                };
+
+                let function = format!("{}::{}", name, method);
+
+                match self
+                    .functions
+                    .iter()
+                    .rposition(|item| item.name == function)
+                {
+                    Some(function) => {
+                        self.execute(bytecode::ByteNode::Call((function, argc)), base, pointer)?
+                    }
+                    None => return Err(error_handler::RuntimeErrorKind::UnknownMethod(function)),
+                }
+            }
+            bytecode::ByteNode::CallNative((native, argc)) => {
+                let native = match self.natives.get(native) {
+                    Some(native) => native.to_owned(),
+                    None => return Err(error_handler::RuntimeErrorKind::UnknownFunction(native)),
+                };
+
+                if native.params.len() != argc {
+                    return Err(error_handler::RuntimeErrorKind::ArityMismatch(format!(
+                        "function \"{}\" takes {} arguments but {} were given",
+                        native.name,
+                        native.params.len(),
+                        argc
+                    )));
+                }
+
+                let stack = self.stack();
+
+                if argc > stack.len() {
+                    return Err(error_handler::RuntimeErrorKind::StackUnderflow);
+                }
+
+                let mut args = vec![];
+
+                for (index, (arg, r#type)) in stack
+                    .split_off(stack.len() - argc)
+                    .into_iter()
+                    .zip(native.params.iter())
+                    .enumerate()
+                {
+                    let found = operations::type_name(&arg);
+
+                    match operations::convert(arg, r#type) {
+                        Some(arg) => args.push(arg),
+                        None => {
+                            return Err(error_handler::RuntimeErrorKind::TypeMismatch(format!(
+                                "argument {} of \"{}\" must be {} but it's {}",
+                                index + 1,
+                                native.name,
+                                r#type,
+                                found
+                            )))
                         }
                     }
                 }
-                bytecode::Scope::Function(code) => {}
+
+                let value = (native.function)(args).map_err(|message| {
+                    error_handler::RuntimeErrorKind::Native(format!("{}: {}", native.name, message))
+                })?;
+                let found = operations::type_name(&value);
+
+                match operations::convert(value, &native.returns) {
+                    Some(value) => self.stack().push(value),
+                    None => {
+                        return Err(error_handler::RuntimeErrorKind::TypeMismatch(format!(
+                            "\"{}\" must return {} but it returned {}",
+                            native.name, native.returns, found
+                        )))
+                    }
+                }
+            }
+            bytecode::ByteNode::Ret => {
+                if self.stack_memory.len() <= base {
+                    return Err(error_handler::RuntimeErrorKind::NoFrame);
+                }
+
+                let value = self.pop()?;
+
+                // Drop the frame and give the value to the caller
+                self.stack_memory.pop();
+                self.stack().push(value);
             }
         }
+
+        Ok(())
     }
 }
 
-/*
-entry:
-    push(Value(Int(1)))     # [1]
-    push(Value(Int(10)))    # [1, 10]
-    push(Value(Int(2)))     # [1, 10, 2]
-    mul                     # [1, 20]
-    call test
-test:
-    pull args.1
-    ret
-*/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a global code and return the error it fails with
+    fn run_error(code: Vec<bytecode::ByteNode>) -> error_handler::RuntimeError {
+        let mut runtime = Runtime::new();
+
+        match runtime.run_byte(bytecode::ByteCode {
+            code: vec![bytecode::Scope::Global(code)],
+        }) {
+            Err(error_handler::Error::RuntimeError(error)) => error,
+            result => panic!("expected a runtime error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn pop_out_of_the_global_stack_is_a_bad_global_index() {
+        let error = run_error(vec![
+            bytecode::ByteNode::Push(bytecode::Value::Int(1)),
+            bytecode::ByteNode::Pop(3),
+        ]);
+
+        assert!(matches!(
+            error.kind(),
+            error_handler::RuntimeErrorKind::BadIndex(bytecode::ValueScope::Global, 3)
+        ));
+        assert_eq!(error.location().to_string(), "global:1");
+    }
+
+    #[test]
+    fn mov_out_of_a_function_stack_is_a_bad_local_index() {
+        let mut runtime = Runtime::new();
+        let result = runtime.run_byte(bytecode::ByteCode {
+            code: vec![
+                bytecode::Scope::Function {
+                    name: "f".to_string(),
+                    params: 0,
+                    code: vec![bytecode::ByteNode::Mov(0)],
+                },
+                bytecode::Scope::Global(vec![bytecode::ByteNode::Call((0, 0))]),
+            ],
+        });
+
+        match result {
+            Err(error_handler::Error::RuntimeError(error)) => {
+                assert!(matches!(
+                    error.kind(),
+                    error_handler::RuntimeErrorKind::BadIndex(bytecode::ValueScope::Local, 0)
+                ));
+                assert_eq!(error.location().to_string(), "f:0");
+            }
+            result => panic!("expected a runtime error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn missing_operands_are_a_stack_underflow() {
+        let error = run_error(vec![
+            bytecode::ByteNode::Push(bytecode::Value::Int(1)),
+            bytecode::ByteNode::Add,
+        ]);
+
+        assert!(matches!(
+            error.kind(),
+            error_handler::RuntimeErrorKind::StackUnderflow
+        ));
+        assert_eq!(error.location().to_string(), "global:1");
+    }
+
+    #[test]
+    fn pull_of_a_missing_global_is_a_bad_index() {
+        let error = run_error(vec![bytecode::ByteNode::Pull((
+            bytecode::ValueScope::Global,
+            2,
+        ))]);
+
+        assert!(matches!(
+            error.kind(),
+            error_handler::RuntimeErrorKind::BadIndex(bytecode::ValueScope::Global, 2)
+        ));
+    }
+
+    #[test]
+    fn call_of_a_missing_function_is_a_unknown_function() {
+        let error = run_error(vec![bytecode::ByteNode::Call((4, 0))]);
+
+        assert!(matches!(
+            error.kind(),
+            error_handler::RuntimeErrorKind::UnknownFunction(4)
+        ));
+    }
+
+    #[test]
+    fn ret_in_the_global_code_has_no_frame() {
+        let error = run_error(vec![
+            bytecode::ByteNode::Push(bytecode::Value::Null),
+            bytecode::ByteNode::Ret,
+        ]);
+
+        assert!(matches!(
+            error.kind(),
+            error_handler::RuntimeErrorKind::NoFrame
+        ));
+        assert_eq!(error.location().to_string(), "global:1");
+    }
+
+    #[test]
+    fn recursion_without_end_is_a_stack_overflow() {
+        let mut runtime = Runtime::new();
+        let result = runtime.run_byte(bytecode::ByteCode {
+            code: vec![
+                bytecode::Scope::Function {
+                    name: "f".to_string(),
+                    params: 0,
+                    code: vec![bytecode::ByteNode::Call((0, 0))],
+                },
+                bytecode::Scope::Global(vec![bytecode::ByteNode::Call((0, 0))]),
+            ],
+        });
+
+        match result {
+            Err(error_handler::Error::RuntimeError(error)) => {
+                assert!(matches!(
+                    error.kind(),
+                    error_handler::RuntimeErrorKind::StackOverflow(function) if function == "f"
+                ));
+                assert_eq!(error.location().to_string(), "f:0");
+            }
+            result => panic!("expected a runtime error, got {:?}", result),
+        }
+
+        // The frames of the failed calls are dropped
+        assert!(runtime.stack_memory.is_empty());
+    }
+
+    #[test]
+    fn call_with_the_wrong_arguments_fails_in_the_function() {
+        let mut runtime = Runtime::new();
+        runtime
+            .run_byte(bytecode::ByteCode {
+                code: vec![bytecode::Scope::Function {
+                    name: "f".to_string(),
+                    params: 1,
+                    code: vec![
+                        bytecode::ByteNode::Pull((bytecode::ValueScope::Args, 0)),
+                        bytecode::ByteNode::Ret,
+                    ],
+                }],
+            })
+            .unwrap();
+
+        match runtime.call(0, vec![]) {
+            Err(error_handler::Error::RuntimeError(error)) => {
+                assert!(matches!(
+                    error.kind(),
+                    error_handler::RuntimeErrorKind::ArityMismatch(_)
+                ));
+                assert_eq!(error.location().to_string(), "f:0");
+            }
+            result => panic!("expected a runtime error, got {:?}", result),
+        }
+
+        assert_eq!(
+            runtime
+                .call(0, vec![bytecode::Value::Int(2)])
+                .unwrap()
+                .to_string(),
+            "2"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::{eval, runtime_error};
+use srzs_lang::{Engine, Value};
+
+#[test]
+fn int_overflow_is_a_error() {
+    let mut engine = Engine::new();
+
+    assert_eq!(
+        runtime_error(&mut engine, "let max = 2147483647; let next = max + 1;"),
+        "[E014] runtime: integer overflow in \"2147483647 + 1\". (global:3)"
+    );
+    assert_eq!(
+        runtime_error(
+            &mut engine,
+            "let min = -2147483647 - 1; let next = min * -1;"
+        ),
+        "[E014] runtime: integer overflow in \"-2147483648 * -1\". (global:7)"
+    );
+    assert_eq!(
+        runtime_error(
+            &mut engine,
+            "let min = -2147483647 - 1; let negated = -min;"
+        ),
+        "[E014] runtime: integer overflow in \"--2147483648\". (global:5)"
+    );
+}
+
+#[test]
+fn division_and_rest_by_zero_are_errors() {
+    let mut engine = Engine::new();
+
+    assert_eq!(
+        runtime_error(&mut engine, "let zero = 0; let result = 1 / zero;"),
+        "[E013] runtime: division by zero. (global:3)"
+    );
+    assert_eq!(
+        runtime_error(&mut engine, "let zero = 0.0; let result = 1.5 % zero;"),
+        "[E013] runtime: division by zero. (global:3)"
+    );
+}
+
+#[test]
+fn errors_in_functions_are_located_in_them() {
+    let mut engine = Engine::new();
+    let source = "fn divide(a: int, b: int) -> int { return a / b; }";
+
+    eval(&mut engine, source);
+
+    let error = engine
+        .call_function("divide", vec![Value::Int(1), Value::Int(0)])
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "[E013] runtime: division by zero. (divide:8)"
+    );
+
+    assert_eq!(
+        runtime_error(&mut engine, "let rest = divide(7, 0) % 2;"),
+        "[E013] runtime: division by zero. (divide:8)"
+    );
+
+    let value = engine
+        .call_function("divide", vec![Value::Int(-7), Value::Int(2)])
+        .unwrap();
+    assert_eq!(value.to_string(), "-3");
+}
+
+#[test]
+fn numbers_follow_the_promotion_rules() {
+    let mut engine = Engine::new();
+
+    eval(&mut engine, "let a = 7 % -2; let b = -7 / 2; let c = 1 + 0.5; let d = 1.0 / 0.5; let e = \"a\" + \"b\";");
+
+    assert_eq!(engine.get_global("a").unwrap().to_string(), "1");
+    assert_eq!(engine.get_global("b").unwrap().to_string(), "-3");
+    assert_eq!(engine.get_global("c").unwrap().to_string(), "1.5");
+    assert_eq!(engine.get_global("d").unwrap().to_string(), "2.0");
+    assert_eq!(engine.get_global("e").unwrap().to_string(), "ab");
+}
@@ -0,0 +1,119 @@
+//! Helpers shared by the engine tests, each test file uses only some of them
+#![allow(dead_code)]
+
+use srzs_lang::{Engine, Error};
+use std::cell::RefCell;
+use std::io::Write;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// A output shared with the test, the engine keeps the other reference
+#[derive(Clone, Default)]
+pub struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+impl Buffer {
+    pub fn text(&self) -> String {
+        String::from_utf8(self.0.borrow().to_owned()).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(data)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Make "std::io" print in a buffer and return it
+pub fn capture(engine: &mut Engine) -> Buffer {
+    let output = Buffer::default();
+    engine.set_output(output.clone());
+
+    output
+}
+
+/// Evaluate a source and panic with its errors when it fails
+pub fn eval(engine: &mut Engine, source: &str) {
+    if let Err(errors) = engine.eval_str(source) {
+        panic!(
+            "{:?}",
+            errors.iter().map(Error::to_string).collect::<Vec<_>>()
+        );
+    }
+}
+
+/// Evaluate a source in a new engine and return what it printed
+pub fn output(source: &str) -> String {
+    let mut engine = Engine::new();
+    let output = capture(&mut engine);
+
+    eval(&mut engine, source);
+
+    output.text()
+}
+
+/// Evaluate a source that must fail and return its errors as text
+pub fn eval_errors(engine: &mut Engine, source: &str) -> Vec<String> {
+    match engine.eval_str(source) {
+        Ok(()) => panic!("expected \"{}\" to fail", source),
+        Err(errors) => errors.iter().map(Error::to_string).collect(),
+    }
+}
+
+/// Evaluate a source that must fail and return its only error as text
+pub fn eval_error(engine: &mut Engine, source: &str) -> String {
+    let errors = eval_errors(engine, source);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+
+    errors[0].to_owned()
+}
+
+/// Evaluate a source that must fail with a runtime error and return it as text
+pub fn runtime_error(engine: &mut Engine, source: &str) -> String {
+    match engine.eval_str(source) {
+        Ok(()) => panic!("expected \"{}\" to fail", source),
+        Err(errors) => match errors.as_slice() {
+            [Error::RuntimeError(error)] => error.to_string(),
+            errors => panic!("expected a runtime error, got {:?}", errors),
+        },
+    }
+}
+
+/// The codes of the errors of a source in a new engine, empty when it runs
+pub fn codes(source: &str) -> Vec<String> {
+    match Engine::new().eval_str(source) {
+        Ok(()) => vec![],
+        Err(errors) => errors
+            .iter()
+            .map(|error| code(&error.to_string()))
+            .collect(),
+    }
+}
+
+/// The code of a error as text -> "[E013] runtime: ..." <- E013
+pub fn code(error: &str) -> String {
+    error
+        .strip_prefix('[')
+        .and_then(|error| error.split_once(']'))
+        .map(|(code, _)| code.to_string())
+        .unwrap_or_default()
+}
+
+/// The value of a global as text, "none" when it doesn't exist
+pub fn global(engine: &Engine, name: &str) -> String {
+    engine
+        .get_global(name)
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+/// A new directory for the files of a test
+pub fn directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("srzs-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
@@ -0,0 +1,189 @@
+mod common;
+
+use common::{codes, eval};
+use srzs_lang::Engine;
+
+#[test]
+fn names_are_declared_once_in_a_scope() {
+    assert_eq!(codes("let a = 1; let a = 2;"), ["E031"]);
+    assert_eq!(codes("fn f() { let a = 1; let a = 2; }"), ["E031"]);
+    assert_eq!(codes("fn f() {} fn f() {}"), ["E031"]);
+    assert_eq!(codes("fn f(a: int, a: int) {}"), ["E031"]);
+    assert_eq!(
+        codes("struct Point { x: int } struct Point { y: int }"),
+        ["E031"]
+    );
+    assert_eq!(codes("struct Point { x: int, x: int }"), ["E027"]);
+}
+
+#[test]
+fn constants_are_declared_once_in_a_scope() {
+    assert_eq!(codes("const A = 1; const A = 2;"), ["E031"]);
+    assert_eq!(codes("fn f() { const A = 1; const A = 2; }"), ["E031"]);
+    assert_eq!(
+        codes("fn f() { const A = 1; if true { const B = 2; } const B = 3; const A = 4; }"),
+        ["E031"]
+    );
+}
+
+#[test]
+fn names_are_declared_before_they_are_used() {
+    assert_eq!(codes("let a = missing + 1;"), ["E004"]);
+    assert_eq!(codes("missing = 1;"), ["E004"]);
+    assert_eq!(
+        codes("fn f() -> int { let x = 1; return x; } let y = x;"),
+        ["E004"]
+    );
+    assert_eq!(codes("let a = missing(1);"), ["E005"]);
+    assert_eq!(codes("let a: Missing = 1;"), ["E025"]);
+    assert_eq!(codes("fn f(p: Missing) {}"), ["E025"]);
+    assert_eq!(codes("fn f() -> Missing { return 1; }"), ["E025"]);
+    assert!(codes("fn f() -> int { return g(); } fn g() -> int { return 1; }").is_empty());
+}
+
+#[test]
+fn inner_scopes_can_shadow_names() {
+    assert!(codes("let a = 1; if true { let a = \"inner\"; }").is_empty());
+    assert!(codes("const A = 1; fn f() -> int { const A = 2; return A; }").is_empty());
+    assert!(codes("const A = 1; if true { const A = 2; } if true { const A = 3; }").is_empty());
+}
+
+#[test]
+fn a_later_source_can_declare_the_names_again() {
+    let mut engine = Engine::new();
+
+    eval(&mut engine, "let a = 1; const B = 2; fn f() {}");
+    eval(&mut engine, "let a = 3; const B = 4; fn f() {}");
+}
+
+#[test]
+fn immutable_variables_are_not_reassigned() {
+    assert_eq!(codes("let a = 1; a = 2;"), ["E032"]);
+    assert_eq!(codes("fn f() { let a = 1; a = 2; }"), ["E032"]);
+    assert!(codes("let mut a = 1; a = 2; fn f() { let mut b = 1; b = a; }").is_empty());
+}
+
+#[test]
+fn immutable_variables_have_one_late_initialization() {
+    assert!(codes("let a: int; a = 1;").is_empty());
+    assert!(codes("let a: int; if true { a = 1; } else { a = 2; }").is_empty());
+    assert_eq!(codes("let a: int; a = 1; a = 2;"), ["E032"]);
+    assert_eq!(codes("let a: int; if true { a = 1; } a = 2;"), ["E032"]);
+    assert_eq!(codes("let a: int; while true { a = 1; }"), ["E032"]);
+}
+
+#[test]
+fn compound_assignments_change_the_variable() {
+    assert_eq!(codes("let a = 1; a += 2;"), ["E032"]);
+    assert_eq!(codes("let a: int; a += 2;"), ["E032"]);
+    assert!(codes("let mut a = 1; a += 2; a *= 3;").is_empty());
+}
+
+#[test]
+fn void_is_only_a_return_type() {
+    assert_eq!(codes("fn f(a: void) {}"), ["E030"]);
+    assert_eq!(
+        codes("fn another_function(param1: int, param2: void, param3: float) {}"),
+        ["E030"]
+    );
+    assert_eq!(codes("fn f() -> void {} let a = f();"), ["E030"]);
+    assert!(codes("fn f() -> void {} f();").is_empty());
+}
+
+#[test]
+fn values_match_the_declared_types() {
+    assert_eq!(codes("let a: int = \"one\";"), ["E029"]);
+    assert_eq!(codes("let mut a = 1; a = true;"), ["E029"]);
+    assert_eq!(codes("fn f(a: str) {} f(1);"), ["E029"]);
+    assert_eq!(codes("fn f() -> int { return \"one\"; }"), ["E029"]);
+    assert_eq!(codes("let a = 1 + true;"), ["E029"]);
+    assert!(codes("let a: float = 1; fn f(b: float) -> float { return b; } f(2);").is_empty());
+}
+
+#[test]
+fn functions_with_a_value_return_it() {
+    assert_eq!(codes("fn f() -> int { if true { return 1; } }"), ["E034"]);
+    assert_eq!(codes("fn f() -> int {}"), ["E034"]);
+    assert!(codes("fn f() -> int { if true { return 1; } else { return 2; } }").is_empty());
+
+    // A loop that only ends with a return can't reach the end
+    assert!(codes("fn f() -> int { while true { return 1; } }").is_empty());
+    assert!(codes(
+        "fn f(c: bool) -> int { let mut x = 0; while true { if c { return x; } x += 1; } }"
+    )
+    .is_empty());
+    assert!(codes("fn f() -> int { for ;; { return 1; } }").is_empty());
+    assert!(codes("fn f() -> int { while true { for ;; { break; } } }").is_empty());
+    assert_eq!(codes("fn f() -> int { while true { break; } }"), ["E034"]);
+    assert_eq!(
+        codes("fn f(c: bool) -> int { for ;; { if c { break; } return 1; } }"),
+        ["E034"]
+    );
+    assert_eq!(
+        codes("fn f(c: bool) -> int { while c { return 1; } }"),
+        ["E034"]
+    );
+}
+
+#[test]
+fn variables_have_a_value_before_they_are_read() {
+    assert_eq!(codes("let a: int; let b = a;"), ["E033"]);
+    assert_eq!(codes("let a: int; if true { a = 1; } let b = a;"), ["E033"]);
+    assert!(codes("let a: int; if true { a = 1; } else { a = 2; } let b = a;").is_empty());
+}
+
+#[test]
+fn functions_read_module_variables_with_a_value() {
+    assert_eq!(
+        codes("let a: int; fn f() -> int { return a; } f(); a = 1;"),
+        ["E033"]
+    );
+    assert_eq!(
+        codes("let a: int; f(); a = 1; fn f() -> int { return a; }"),
+        ["E033"]
+    );
+    assert!(codes("let a: int; a = 1; fn f() -> int { return a; } f();").is_empty());
+    assert!(codes("let a = 1; f(); fn f() -> int { return a; }").is_empty());
+}
+
+#[test]
+fn loops_without_a_condition_end_with_their_breaks() {
+    assert!(codes("let x: int; for ;; { x = 1; break; } let y = x;").is_empty());
+    assert!(codes("let mut x: int; while true { x = 1; break; } let y = x;").is_empty());
+    assert!(
+        codes("fn f(c: bool) { let x: int; for ;; { if c { x = 1; break; } } let y = x; }")
+            .is_empty()
+    );
+    assert_eq!(
+        codes("let mut x: int; for ;; { if true { break; } x = 1; } let y = x;"),
+        ["E033"]
+    );
+    assert_eq!(
+        codes("fn f(c: bool) { let mut x: int; for ;; { if c { x = 1; break; } if c { break; } } let y = x; }"),
+        ["E033"]
+    );
+
+    // A loop with a condition can end before the first iteration
+    assert_eq!(
+        codes("fn f(c: bool) { let mut x: int; while c { x = 1; break; } let y = x; }"),
+        ["E033"]
+    );
+    assert!(codes(
+        "let x: int; for let mut i = 0; i < 3; i += 1 { if i == 1 { x = i; break; } } else { x = 0; } let y = x;"
+    )
+    .is_empty());
+}
+
+#[test]
+fn immutable_variables_are_initialized_in_one_iteration() {
+    assert_eq!(
+        codes("fn f(c: bool) { let x: int; while c { x = 1; } }"),
+        ["E032"]
+    );
+    assert_eq!(
+        codes("fn f(c: bool) { let x: int; while c { if c { x = 1; continue; } break; } }"),
+        ["E032"]
+    );
+    assert!(codes("fn f(c: bool) { let x: int; while c { x = 1; break; } }").is_empty());
+    assert!(codes("fn f(c: bool) { while c { let x: int; x = 1; } }").is_empty());
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use common::{codes, output};
+
+#[test]
+fn constants_are_evaluated_from_other_constants() {
+    let printed = output(
+        "use std::io; const A := 2; const B := A * 3 + 1; const AREA: float = A * B; const NAME := \"srzs\" + \"!\"; io::println(B); io::println(AREA); io::println(NAME);",
+    );
+
+    assert_eq!(printed, "7\n14.0\nsrzs!\n");
+}
+
+#[test]
+fn invalid_constants_are_reported() {
+    assert_eq!(codes("let mut v = 1; const C: int = v;"), ["E024"]);
+    assert_eq!(
+        codes("fn f() -> int { return 1; } const C := f();"),
+        ["E024"]
+    );
+    assert_eq!(codes("const C: int = \"s\";"), ["E024"]);
+    assert_eq!(codes("const Z := 1 / 0;"), ["E024"]);
+    assert_eq!(codes("const C := 1; C = 2;"), ["E024"]);
+}
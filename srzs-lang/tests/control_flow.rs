@@ -0,0 +1,42 @@
+mod common;
+
+use common::{codes, output};
+
+#[test]
+fn if_chains_run_the_first_true_branch() {
+    let printed = output(
+        "use std::io; fn sign(n: int) -> str { if n > 0 { return \"positive\"; } elif n < 0 { return \"negative\"; } elif false { return \"never\"; } else { return \"zero\"; } } io::println(sign(3)); io::println(sign(-3)); io::println(sign(0)); if false { io::println(\"skipped\"); } if true { io::println(\"ran\"); } else { io::println(\"skipped\"); }",
+    );
+
+    assert_eq!(printed, "positive\nnegative\nzero\nran\n");
+}
+
+#[test]
+fn invalid_if_chains_are_reported() {
+    assert_eq!(codes("if true { } else { } elif true { }"), ["E002"]);
+    assert_eq!(codes("if true let a = 1;"), ["E002"]);
+}
+
+#[test]
+fn loops_break_and_continue() {
+    let printed = output(
+        "use std::io; let mut i = 0; let mut odd = 0; while true { i += 1; if i % 2 == 0 { continue; } if i > 7 { break; } odd += i; } io::println(i); io::println(odd); let mut sum = 0; for let mut j = 0; j < 10; j += 1 { if j == 3 { continue; } if j == 6 { break; } sum += j; } io::println(sum); let mut n = 0; for ; ; { n += 1; if n == 4 { break; } } io::println(n);",
+    );
+
+    assert_eq!(printed, "9\n16\n12\n4\n");
+}
+
+#[test]
+fn for_else_runs_when_the_loop_does_not_break() {
+    let printed = output(
+        "use std::io; fn find(target: int) -> void { for let mut j = 0; j < 3; j += 1 { if j == target { io::println(\"found\"); break; } } else { io::println(\"missing\"); } } find(1); find(5);",
+    );
+
+    assert_eq!(printed, "found\nmissing\n");
+}
+
+#[test]
+fn loop_control_outside_a_loop_is_reported() {
+    assert_eq!(codes("break;"), ["E007"]);
+    assert_eq!(codes("fn f() -> void { continue; }"), ["E007"]);
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use common::{code, directory, eval, eval_errors, global};
+use srzs_lang::{Engine, Value};
+
+#[test]
+fn eval_str_keeps_globals_and_functions() {
+    let mut engine = Engine::new();
+
+    eval(
+        &mut engine,
+        "let mut count = 2; fn twice(n: int) -> int { return n * 2; }",
+    );
+    eval(&mut engine, "count = twice(count) + 1;");
+
+    assert_eq!(global(&engine, "count"), "5");
+    assert_eq!(global(&engine, "missing"), "none");
+}
+
+#[test]
+fn eval_file_runs_the_file_and_finds_its_modules() {
+    let directory = directory("eval-file");
+    std::fs::write(
+        directory.join("helper.srzs"),
+        "pub fn greeting() -> str { return \"hello\"; }",
+    )
+    .unwrap();
+    std::fs::write(
+        directory.join("main.srzs"),
+        "use helper; let message = helper::greeting();",
+    )
+    .unwrap();
+
+    let mut engine = Engine::new();
+    let result = engine.eval_file(directory.join("main.srzs").to_str().unwrap());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert!(result.is_ok(), "{:?}", result);
+    assert_eq!(global(&engine, "message"), "hello");
+}
+
+#[test]
+fn eval_file_reports_a_missing_file() {
+    let mut engine = Engine::new();
+    let errors = engine.eval_file("missing/file.srzs").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(code(&errors[0].to_string()), "E018", "{}", errors[0]);
+}
+
+#[test]
+fn call_function_returns_the_value() {
+    let mut engine = Engine::new();
+    eval(
+        &mut engine,
+        "fn add(a: int, b: float) -> float { return a + b; }",
+    );
+
+    // The int is converted to the float parameter
+    let value = engine
+        .call_function("add", vec![Value::Int(1), Value::Int(2)])
+        .unwrap();
+
+    assert!(matches!(value, Value::Float(value) if value == 3.0));
+}
+
+#[test]
+fn call_function_reports_errors() {
+    let mut engine = Engine::new();
+    eval(&mut engine, "fn half(n: int) -> int { return n / 2; }");
+
+    let missing = engine.call_function("double", vec![]).unwrap_err();
+    assert_eq!(code(&missing.to_string()), "E005", "{}", missing);
+
+    let arity = engine.call_function("half", vec![]).unwrap_err();
+    assert_eq!(code(&arity.to_string()), "E016", "{}", arity);
+    assert!(arity.to_string().ends_with("(half:0)"), "{}", arity);
+
+    let mismatch = engine
+        .call_function("half", vec![Value::String("two".to_string())])
+        .unwrap_err();
+    assert_eq!(code(&mismatch.to_string()), "E012", "{}", mismatch);
+
+    // The engine can still call it after the errors
+    let value = engine.call_function("half", vec![Value::Int(8)]).unwrap();
+    assert_eq!(value.to_string(), "4");
+}
+
+#[test]
+fn set_global_is_read_by_scripts() {
+    let mut engine = Engine::new();
+
+    engine.set_global("limit", Value::Int(3)).unwrap();
+    eval(&mut engine, "let doubled = limit * 2;");
+
+    assert_eq!(global(&engine, "doubled"), "6");
+}
+
+#[test]
+fn set_global_converts_to_the_declared_type() {
+    let mut engine = Engine::new();
+    eval(&mut engine, "let ratio: float = 0.5; let name = \"srzs\";");
+
+    engine.set_global("ratio", Value::Int(2)).unwrap();
+    assert!(matches!(engine.get_global("ratio"), Some(Value::Float(value)) if value == 2.0));
+
+    let error = engine.set_global("name", Value::Int(2)).unwrap_err();
+    assert_eq!(code(&error.to_string()), "E029", "{}", error);
+    assert_eq!(global(&engine, "name"), "srzs");
+}
+
+#[test]
+fn failed_compilation_keeps_nothing() {
+    let mut engine = Engine::new();
+    eval(&mut engine, "let mut count = 1;");
+
+    let errors = eval_errors(&mut engine, "let added = 2; count = \"text\";");
+    assert_eq!(code(&errors[0]), "E029", "{:?}", errors);
+
+    assert_eq!(global(&engine, "added"), "none");
+    assert_eq!(global(&engine, "count"), "1");
+
+    // The names of the failed source can be declared again
+    eval(&mut engine, "let added = 3;");
+    assert_eq!(global(&engine, "added"), "3");
+}
+
+#[test]
+fn failed_run_restores_the_globals() {
+    let mut engine = Engine::new();
+    eval(&mut engine, "let mut count = 1; let zero = 0;");
+
+    let errors = eval_errors(
+        &mut engine,
+        "let added = 2; count = 10; fn broken() -> int { return 1; } count = count / zero;",
+    );
+    assert_eq!(code(&errors[0]), "E013", "{:?}", errors);
+
+    assert_eq!(global(&engine, "count"), "1");
+    assert_eq!(global(&engine, "added"), "none");
+    assert!(engine.call_function("broken", vec![]).is_err());
+
+    eval(&mut engine, "count = count + 1;");
+    assert_eq!(global(&engine, "count"), "2");
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use common::{capture, eval, Buffer};
+use srzs_lang::{Engine, Value};
+use std::io::Cursor;
+
+#[test]
+fn print_functions_write_to_the_outputs() {
+    let mut engine = Engine::new();
+    let output = capture(&mut engine);
+    let error = Buffer::default();
+    engine.set_error_output(error.clone());
+
+    eval(
+        &mut engine,
+        "use std::io; io::println(\"Hello, World!\"); io::print(1 + 2); io::print(' '); io::println(1.5); io::eprintln(\"failed\");",
+    );
+
+    assert_eq!(output.text(), "Hello, World!\n3 1.5\n");
+    assert_eq!(error.text(), "failed\n");
+}
+
+#[test]
+fn read_line_returns_the_lines_and_null_in_the_end() {
+    let mut engine = Engine::new();
+    let output = capture(&mut engine);
+    engine.set_input(Cursor::new("first\r\nsecond\n\nlast"));
+
+    eval(
+        &mut engine,
+        "use std::io; let mut line = io::read_line(); while line != null { io::println(\"<\" + line + \">\"); line = io::read_line(); }",
+    );
+
+    assert_eq!(output.text(), "<first>\n<second>\n<>\n<last>\n");
+
+    // Reading again in the end of the input is still null
+    eval(&mut engine, "use std::io; let after = io::read_line();");
+    assert!(matches!(engine.get_global("after"), Some(Value::Null)));
+}
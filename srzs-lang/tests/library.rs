@@ -0,0 +1,134 @@
+mod common;
+
+use common::{code, eval, eval_error, global};
+use srzs_lang::{Engine, Value};
+
+#[test]
+fn sort_orders_numbers_strs_and_chars() {
+    let mut engine = Engine::new();
+
+    eval(
+        &mut engine,
+        "use std::array; let numbers = array::sort([3, 1.5, -2, 16777217, 16777216.0, 0]); let words = array::sort([\"pear\", \"apple\", \"fig\"]); let chars = array::sort(['c', 'a', 'b']); let empty = array::sort([]);",
+    );
+
+    assert_eq!(
+        global(&engine, "numbers"),
+        "[-2, 0, 1.5, 3, 16777216.0, 16777217]"
+    );
+    assert_eq!(global(&engine, "words"), "[apple, fig, pear]");
+    assert_eq!(global(&engine, "chars"), "[a, b, c]");
+    assert_eq!(global(&engine, "empty"), "[]");
+}
+
+#[test]
+fn sort_rejects_nan() {
+    let mut engine = Engine::new();
+
+    // Long enough for the sort of the standard library to check the order
+    let error = eval_error(
+        &mut engine,
+        "use std::array; use std::math; let values = [25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, math::sqrt(-1.0), 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]; array::sort(values);",
+    );
+    assert_eq!(code(&error), "E019", "{}", error);
+    assert!(error.contains("can't sort a array with NaN"), "{}", error);
+
+    let error = eval_error(
+        &mut engine,
+        "use std::array; use std::math; array::sort([1.0, math::sqrt(-1.0)]);",
+    );
+    assert!(error.contains("can't sort a array with NaN"), "{}", error);
+}
+
+#[test]
+fn sort_rejects_values_that_can_not_be_compared() {
+    let mut engine = Engine::new();
+
+    let error = eval_error(&mut engine, "use std::array; array::sort([1, \"one\"]);");
+    assert_eq!(code(&error), "E019", "{}", error);
+    assert!(
+        error.contains("can't sort, type mismatch: can't compare int with str"),
+        "{}",
+        error
+    );
+
+    let error = eval_error(&mut engine, "use std::array; array::sort(['a', 'b', 2.5]);");
+    assert!(error.contains("can't compare char with float"), "{}", error);
+
+    let error = eval_error(&mut engine, "use std::array; array::sort([[1], [2]]);");
+    assert!(
+        error.contains("can't compare array with array"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn math_keeps_ints_as_ints() {
+    let mut engine = Engine::new();
+
+    eval(
+        &mut engine,
+        "use std::math; let absolute: int = math::abs(-3); let biggest = math::max(16777217, 0); let clamped: int = math::clamp(15, 0, 10); let power: int = math::pow(2, 10); let rounded: int = math::round(7);",
+    );
+
+    assert!(matches!(engine.get_global("absolute"), Some(Value::Int(3))));
+    assert!(matches!(
+        engine.get_global("biggest"),
+        Some(Value::Int(16777217))
+    ));
+    assert!(matches!(engine.get_global("clamped"), Some(Value::Int(10))));
+    assert!(matches!(engine.get_global("power"), Some(Value::Int(1024))));
+    assert!(matches!(engine.get_global("rounded"), Some(Value::Int(7))));
+}
+
+#[test]
+fn math_promotes_to_float_with_a_float_argument() {
+    let mut engine = Engine::new();
+
+    eval(
+        &mut engine,
+        "use std::math; let absolute = math::abs(-2.5); let smallest = math::min(1, 2.5); let power = math::pow(2, -1); let floored = math::floor(2.7); let root = math::sqrt(16); let truncated: int = math::to_int(2.7);",
+    );
+
+    assert_eq!(global(&engine, "absolute"), "2.5");
+    assert_eq!(global(&engine, "smallest"), "1.0");
+    assert_eq!(global(&engine, "power"), "0.5");
+    assert_eq!(global(&engine, "floored"), "2.0");
+    assert_eq!(global(&engine, "root"), "4.0");
+    assert_eq!(global(&engine, "truncated"), "2");
+}
+
+#[test]
+fn math_reports_int_overflow_and_invalid_arguments() {
+    let mut engine = Engine::new();
+
+    let error = eval_error(&mut engine, "use std::math; math::abs(math::INT_MIN);");
+    assert_eq!(code(&error), "E019", "{}", error);
+    assert!(
+        error.contains("std::math::abs: integer overflow"),
+        "{}",
+        error
+    );
+
+    let error = eval_error(&mut engine, "use std::math; math::pow(2, 31);");
+    assert!(
+        error.contains("std::math::pow: integer overflow"),
+        "{}",
+        error
+    );
+
+    let error = eval_error(&mut engine, "use std::math; math::clamp(1, 10, 0);");
+    assert!(
+        error.contains("the min is greater than the max"),
+        "{}",
+        error
+    );
+
+    let error = eval_error(&mut engine, "use std::math; math::abs(\"three\");");
+    assert!(error.contains("expected a int or a float"), "{}", error);
+
+    // The functions that always give a float check their arguments when the source is compiled
+    let error = eval_error(&mut engine, "use std::math; math::sqrt(\"16\");");
+    assert_eq!(code(&error), "E029", "{}", error);
+}
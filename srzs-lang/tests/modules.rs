@@ -0,0 +1,131 @@
+mod common;
+
+use common::{capture, code, directory};
+use srzs_lang::{Engine, Error};
+
+/// Write the modules of a test in a new directory and run its "main.srzs"
+fn run(name: &str, files: &[(&str, &str)]) -> Result<String, Vec<String>> {
+    let directory = directory(name);
+    for (file, source) in files {
+        std::fs::write(directory.join(file), source).unwrap();
+    }
+
+    let mut engine = Engine::new();
+    let output = capture(&mut engine);
+    let result = engine.eval_file(directory.join("main.srzs").to_str().unwrap());
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    match result {
+        Ok(()) => Ok(output.text()),
+        Err(errors) => Err(errors.iter().map(Error::to_string).collect()),
+    }
+}
+
+fn run_error(name: &str, files: &[(&str, &str)]) -> String {
+    let errors = run(name, files).unwrap_err();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+
+    errors[0].to_owned()
+}
+
+#[test]
+fn use_links_the_module_and_its_items() {
+    let printed = run(
+        "use-links",
+        &[
+            (
+                "shapes.srzs",
+                "pub let sides = 4; pub fn area(side: int) -> int { return side * side; }",
+            ),
+            (
+                "main.srzs",
+                "use std::io; use shapes; use shapes::area; io::println(shapes::area(3) + area(2) + shapes::sides);",
+            ),
+        ],
+    );
+
+    assert_eq!(printed.unwrap(), "17\n");
+}
+
+#[test]
+fn missing_modules_are_reported() {
+    let error = run_error("use-missing", &[("main.srzs", "use missing::module;")]);
+    assert_eq!(code(&error), "E020", "{}", error);
+    assert!(error.contains("\"missing::module\""), "{}", error);
+
+    let error = run_error("use-std-missing", &[("main.srzs", "use std::nothing;")]);
+    assert_eq!(code(&error), "E020", "{}", error);
+}
+
+#[test]
+fn circular_imports_are_reported() {
+    let error = run_error(
+        "use-circular",
+        &[
+            ("a.srzs", "use b; pub fn fa() -> int { return 1; }"),
+            ("b.srzs", "use a; pub fn fb() -> int { return 2; }"),
+            ("main.srzs", "use a; let x = a::fa();"),
+        ],
+    );
+
+    assert_eq!(code(&error), "E022", "{}", error);
+    assert!(error.contains("a -> b -> a"), "{}", error);
+}
+
+#[test]
+fn private_items_stay_in_their_module() {
+    let module = (
+        "hidden.srzs",
+        "let secret = 1; fn helper() -> int { return 2; } struct Point { x: int } const LIMIT := 5; ns inner { pub fn f() -> int { return 3; } } pub ns open { pub fn g() -> int { return 4; } fn h() -> int { return 5; } } pub const SIZE := 6;",
+    );
+
+    for (name, path, main) in [
+        (
+            "pub-let",
+            "hidden::secret",
+            "use hidden; let x = hidden::secret;",
+        ),
+        (
+            "pub-fn",
+            "hidden::helper",
+            "use hidden; let x = hidden::helper();",
+        ),
+        (
+            "pub-struct",
+            "hidden::Point",
+            "use hidden; let p = hidden::Point { x: 1 };",
+        ),
+        (
+            "pub-const",
+            "hidden::LIMIT",
+            "use hidden; let x = hidden::LIMIT;",
+        ),
+        (
+            "pub-ns",
+            "hidden::inner::f",
+            "use hidden; let x = hidden::inner::f();",
+        ),
+        (
+            "pub-ns-fn",
+            "hidden::open::h",
+            "use hidden; let x = hidden::open::h();",
+        ),
+    ] {
+        let error = run_error(name, &[module, ("main.srzs", main)]);
+
+        assert_eq!(code(&error), "E023", "{}", error);
+        assert!(error.contains(&format!("\"{}\"", path)), "{}", error);
+    }
+
+    let printed = run(
+        "pub-items",
+        &[
+            module,
+            (
+                "main.srzs",
+                "use std::io; use hidden; io::println(hidden::open::g() + hidden::SIZE);",
+            ),
+        ],
+    );
+    assert_eq!(printed.unwrap(), "10\n");
+}
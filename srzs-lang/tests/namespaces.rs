@@ -0,0 +1,22 @@
+mod common;
+
+use common::{codes, output};
+
+#[test]
+fn qualified_paths_reach_namespace_items() {
+    let printed = output(
+        "use std::io; ns outer { let unit = 2; fn f() -> int { return 1; } ns inner { fn g() -> int { return f() * unit; } } } use outer::inner; io::println(outer::f() + outer::unit); io::println(outer::inner::g()); io::println(inner::g());",
+    );
+
+    assert_eq!(printed, "3\n2\n2\n");
+}
+
+#[test]
+fn missing_namespace_items_are_reported() {
+    assert_eq!(codes("ns n { } let x = n::missing();"), ["E005"]);
+    assert_eq!(codes("ns n { } let x = n::missing;"), ["E004"]);
+    assert_eq!(
+        codes("ns n { fn f() -> int { return 1; } } let x = f();"),
+        ["E005"]
+    );
+}
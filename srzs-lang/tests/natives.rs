@@ -0,0 +1,107 @@
+mod common;
+
+use common::{code, eval, eval_error};
+use srzs_lang::{BuiltInTypes, Engine, Error, Value};
+
+/// A engine with host functions under "app"
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+
+    engine.register_native(
+        "app::scale",
+        vec![BuiltInTypes::Float, BuiltInTypes::Int],
+        BuiltInTypes::Float,
+        |args| match args.as_slice() {
+            [Value::Float(value), Value::Int(factor)] => Ok(Value::Float(value * *factor as f32)),
+            _ => Err(format!("unexpected arguments {:?}", args)),
+        },
+    );
+    engine.register_native(
+        "app::count",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Float,
+        |args| match args.as_slice() {
+            // The int is converted to the float return type
+            [Value::String(text)] => Ok(Value::Int(text.chars().count() as i32)),
+            _ => Err("expected a str".to_string()),
+        },
+    );
+    engine.register_native(
+        "app::fail",
+        vec![BuiltInTypes::Str],
+        BuiltInTypes::Void,
+        |args| Err(format!("refused {}", args[0])),
+    );
+    engine.register_native("app::wrong", vec![], BuiltInTypes::Int, |_| {
+        Ok(Value::String("not a int".to_string()))
+    });
+
+    engine
+}
+
+#[test]
+fn native_converts_arguments_and_value() {
+    let mut engine = engine();
+
+    eval(
+        &mut engine,
+        "use app; let scaled = app::scale(1.5, 2); let promoted = app::scale(3, 2); let length = app::count(\"four\");",
+    );
+
+    assert!(matches!(engine.get_global("scaled"), Some(Value::Float(value)) if value == 3.0));
+    // The int argument is promoted to the float parameter
+    assert!(matches!(engine.get_global("promoted"), Some(Value::Float(value)) if value == 6.0));
+    assert!(matches!(engine.get_global("length"), Some(Value::Float(value)) if value == 4.0));
+}
+
+#[test]
+fn native_error_is_a_runtime_error() {
+    let mut engine = engine();
+    let error = eval_error(&mut engine, "use app; app::fail(\"it\");");
+
+    assert_eq!(code(&error), "E019", "{}", error);
+    assert!(error.contains("app::fail: refused it"), "{}", error);
+    assert!(error.ends_with("(global:1)"), "{}", error);
+}
+
+#[test]
+fn native_arguments_are_type_checked() {
+    let mut engine = engine();
+    let error = eval_error(&mut engine, "use app; app::scale(\"two\", 2);");
+
+    assert_eq!(code(&error), "E029", "{}", error);
+
+    // A value only known at runtime is checked by the call
+    let error = eval_error(
+        &mut engine,
+        "use app; let value: any = \"two\"; app::scale(value, 2);",
+    );
+
+    assert_eq!(code(&error), "E012", "{}", error);
+    assert!(error.contains("argument 1 of \"app::scale\""), "{}", error);
+}
+
+#[test]
+fn native_value_is_checked_against_the_return_type() {
+    let mut engine = engine();
+    let error = eval_error(&mut engine, "use app; let value = app::wrong();");
+
+    assert_eq!(code(&error), "E012", "{}", error);
+    assert!(
+        error.contains("\"app::wrong\" must return int"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn native_with_the_wrong_arguments_is_a_compile_error() {
+    let mut engine = engine();
+    let error = eval_error(&mut engine, "use app; app::scale(1.0);");
+
+    assert_eq!(code(&error), "E008", "{}", error);
+    assert!(matches!(
+        engine.eval_str("use app; app::missing();").unwrap_err()[0],
+        Error::ScriptError(_)
+    ));
+}
@@ -0,0 +1,21 @@
+mod common;
+
+use common::output;
+
+#[test]
+fn and_or_skip_the_right_operand() {
+    let printed = output(
+        "use std::io; fn right(value: bool) -> bool { io::println(\"right\"); return value; } io::println(false and right(true)); io::println(true or right(false)); io::println(true and right(false)); io::println(false or right(true));",
+    );
+
+    assert_eq!(printed, "false\ntrue\nright\nfalse\nright\ntrue\n");
+}
+
+#[test]
+fn and_or_guard_the_right_operand() {
+    let printed = output(
+        "use std::io; let values = [1, 2]; let index = 5; io::println(index < 2 and values[index] > 0); io::println(index >= 2 or values[index] > 0);",
+    );
+
+    assert_eq!(printed, "false\ntrue\n");
+}
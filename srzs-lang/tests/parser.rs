@@ -0,0 +1,42 @@
+mod common;
+
+use common::{codes, output};
+
+#[test]
+fn expressions_follow_the_precedence_of_the_operators() {
+    let printed = output(
+        "use std::io; io::println(1 + 2 * 3 - 4 / 2); io::println((1 + 2) * 3); io::println(-2 * 3 + 10 % 4); io::println(2 * 3 > 5 and not false or false); io::println(1 < 2 == true);",
+    );
+
+    assert_eq!(printed, "5\n9\n-4\ntrue\ntrue\n");
+}
+
+#[test]
+fn invalid_expressions_are_reported() {
+    assert_eq!(codes("let x = 1 +;"), ["E002"]);
+    assert_eq!(codes("let x = (1 + 2;"), ["E002"]);
+    assert_eq!(codes("let x = 1 let y = 2;"), ["E002"]);
+    assert_eq!(codes("let x = 1 $ 2;"), ["E001", "E002"]);
+}
+
+#[test]
+fn let_declarations_take_or_infer_their_type() {
+    let printed = output(
+        "use std::io; let a := 2.5; let b: float = 1; let mut c := \"s\"; c = c + \"t\"; io::println(a + b); io::println(c);",
+    );
+
+    assert_eq!(printed, "3.5\nst\n");
+    assert_eq!(codes("let a: int = \"s\";"), ["E029"]);
+    assert_eq!(codes("let a := ;"), ["E002"]);
+}
+
+#[test]
+fn blocks_hold_statements_and_their_own_variables() {
+    let printed = output(
+        "use std::io; fn f() -> int { let a = 1; { let b = a + 1; return b * 10; } } let a = \"outer\"; { let a = 2; io::println(a); } io::println(a); io::println(f());",
+    );
+
+    assert_eq!(printed, "2\nouter\n20\n");
+    assert_eq!(codes("{ let b = 1; } let c = b;"), ["E004"]);
+    assert_eq!(codes("fn f() -> void { let a = 1;"), ["E002"]);
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::{capture, code};
+use srzs_lang::{Engine, Error};
+
+/// Run a sample of the "tests" directory and return what it printed
+fn sample(name: &str) -> Result<String, Vec<String>> {
+    let path = format!("{}/../tests/{}.srzs", env!("CARGO_MANIFEST_DIR"), name);
+    let mut engine = Engine::new();
+    let output = capture(&mut engine);
+    engine.set_error_output(output.clone());
+
+    match engine.eval_file(&path) {
+        Ok(()) => Ok(output.text()),
+        Err(errors) => Err(errors.iter().map(Error::to_string).collect()),
+    }
+}
+
+fn printed(name: &str) -> String {
+    sample(name).unwrap_or_else(|errors| panic!("{} failed: {:?}", name, errors))
+}
+
+#[test]
+fn samples_without_output_run() {
+    for name in ["let_statements", "simple"] {
+        assert_eq!(printed(name), "", "{}", name);
+    }
+}
+
+#[test]
+fn const_statements() {
+    assert_eq!(
+        printed("const_statements"),
+        "200.0\nHello, World!\ntrue\n[20, 10, -2]\nsrzs screen\nsrzs screen: Hello, World!\n"
+    );
+}
+
+#[test]
+fn function_calls() {
+    assert_eq!(printed("function_calls"), "15\n120\n58\n");
+}
+
+#[test]
+fn function_statement() {
+    let errors = sample("function_statement").unwrap_err();
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(code(&errors[0]), "E030", "{}", errors[0]);
+    assert!(errors[0].contains("\"param2\""), "{}", errors[0]);
+}
+
+#[test]
+fn if_statements() {
+    assert_eq!(printed("if_statements"), "5\nout of range\n");
+}
+
+#[test]
+fn imports() {
+    assert_eq!(
+        printed("imports"),
+        "Hello, World!\nHello, srzs!\n2\nHello\nHello, counter!\n"
+    );
+}
+
+#[test]
+fn io_functions() {
+    assert_eq!(
+        printed("io_functions"),
+        "Hello, World!\nSomething went wrong\nfirst line\nsecond line\n2.5\n"
+    );
+}
+
+#[test]
+fn language_test() {
+    assert_eq!(printed("language_test"), "Hello, Some value!\n");
+}
+
+#[test]
+fn loop_statements() {
+    assert_eq!(printed("loop_statements"), "5\n143\n");
+}
+
+#[test]
+fn math_functions() {
+    assert_eq!(
+        printed("math_functions"),
+        "2\n2.5\n4.5\n10\n1024\n0.5\n4.0\n2.0\n7\n314\n1.0\n2147483647\n"
+    );
+}
+
+#[test]
+fn methods() {
+    assert_eq!(
+        printed("methods"),
+        "Vector { x: 1.0, y: 2.0 }\nVector { x: 6.0, y: 8.0 }\n100.0\n12.0\ntrue\nfalse\n"
+    );
+}
+
+#[test]
+fn namespaces() {
+    assert_eq!(printed("namespaces"), "9\n16\n25\n8\n1\n");
+}
+
+#[test]
+fn scopes() {
+    assert_eq!(
+        printed("scopes"),
+        "block\n0\n1\nmodule\nhello world\ninner\n"
+    );
+}
+
+#[test]
+fn string_array_functions() {
+    assert_eq!(
+        printed("string_array_functions"),
+        "HELLO, WORLD!\n13\nHello\n7\nHello, srzs!\nW\none, two, three\n[1, 3, 5, 8]\ntrue\n5\ncba\n3\n"
+    );
+}
+
+#[test]
+fn struct_statements() {
+    assert_eq!(
+        printed("struct_statements"),
+        "shapes::Rect { origin: Point { x: 1.5, y: 2.0 }, width: 8, height: 3, name: window }\n3.5\n24\nsame point\n"
+    );
+}
+
+#[test]
+fn type_checks() {
+    assert_eq!(
+        printed("type_checks"),
+        "2.5\nPoint { x: 2.5, y: 0.0 }\ntwo\nnow a str\n"
+    );
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::{code, codes, output, runtime_error};
+use srzs_lang::Engine;
+
+#[test]
+fn struct_fields_are_read_and_assigned() {
+    let printed = output(
+        "use std::io; struct Point { x: float, y: float } struct Line { start: Point, end: Point } let mut line = Line { end: Point { x: 3, y: 4 }, start: Point { x: 0, y: 0 } }; line.start.x = 1; line.end.y *= 2; io::println(line.end.y - line.start.x); io::println(line); io::println(line.start == Point { x: 1, y: 0 });",
+    );
+
+    assert_eq!(
+        printed,
+        "7.0\nLine { start: Point { x: 1.0, y: 0.0 }, end: Point { x: 3.0, y: 8.0 } }\ntrue\n"
+    );
+}
+
+#[test]
+fn invalid_structs_are_reported() {
+    assert_eq!(codes("struct P { x: int } let p = Q { x: 1 };"), ["E025"]);
+    assert_eq!(codes("struct P { x: int, x: int }"), ["E027"]);
+    assert_eq!(
+        codes("struct P { x: int, y: int } let p = P { x: 1 };"),
+        ["E027"]
+    );
+    assert_eq!(
+        codes("struct P { x: int } let p = P { x: \"s\" };"),
+        ["E027"]
+    );
+    assert_eq!(
+        codes("struct P { x: int } let p = P { x: 1 }; p.x = 2;"),
+        ["E032"]
+    );
+}
+
+#[test]
+fn unknown_fields_are_reported() {
+    assert_eq!(
+        codes("struct P { x: int } let p = P { x: 1, z: 2 };"),
+        ["E026"]
+    );
+    assert_eq!(
+        codes("struct P { x: int } let p = P { x: 1 }; let y = p.z;"),
+        ["E026"]
+    );
+    assert_eq!(
+        codes("struct P { x: int } let mut p = P { x: 1 }; p.z = 2;"),
+        ["E026"]
+    );
+    assert_eq!(
+        codes("struct P { x: int } let mut a; a = P { x: 1 }; let y = a.z;"),
+        ["E026"]
+    );
+}
+
+#[test]
+fn unknown_fields_of_any_values_fail_at_runtime() {
+    let declarations = "struct P { x: int } struct Q { z: int } let mut a; a = P { x: 1 };";
+
+    for source in ["let y = a.z;", "a.z = 2;"] {
+        let error = runtime_error(&mut Engine::new(), &format!("{} {}", declarations, source));
+
+        assert_eq!(code(&error), "E026", "{}", error);
+        assert!(
+            error.contains("struct \"P\" has no field \"z\""),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn impl_methods_are_called_on_their_struct() {
+    let printed = output(
+        "use std::io; struct Counter { count: int } impl Counter { fn new() -> Counter { return Counter { count: 0 }; } fn add(self, amount: int) -> Counter { self.count += amount; return self; } fn get(self) -> int { return self.count; } } let counter = Counter::new().add(2).add(3); io::println(counter.get()); io::println(Counter::new().get());",
+    );
+
+    assert_eq!(printed, "5\n0\n");
+}
+
+#[test]
+fn invalid_method_calls_are_reported() {
+    let declarations =
+        "struct P { x: int } struct Q { x: int } impl P { fn get(self) -> int { return self.x; } }";
+
+    assert_eq!(
+        codes("impl Missing { fn f(self) -> int { return 1; } }"),
+        ["E025"]
+    );
+    assert_eq!(
+        codes(&format!(
+            "{} let p = P {{ x: 1 }}; let y = p.missing();",
+            declarations
+        )),
+        ["E028"]
+    );
+    assert_eq!(
+        codes(&format!("{} let n = 3; let y = n.get();", declarations)),
+        ["E029"]
+    );
+}
+
+#[test]
+fn methods_of_any_values_check_the_receiver_at_runtime() {
+    let declarations = "struct P { x: int } struct Q { x: int } impl P { fn get(self) -> int { return self.x; } } let mut a;";
+
+    let error = runtime_error(
+        &mut Engine::new(),
+        &format!("{} a = Q {{ x: 1 }}; let y = a.get();", declarations),
+    );
+    assert_eq!(code(&error), "E028", "{}", error);
+    assert!(error.contains("\"Q::get\""), "{}", error);
+
+    let error = runtime_error(
+        &mut Engine::new(),
+        &format!("{} a = 3; let y = a.get();", declarations),
+    );
+    assert_eq!(code(&error), "E012", "{}", error);
+    assert!(error.contains("int has no methods"), "{}", error);
+}
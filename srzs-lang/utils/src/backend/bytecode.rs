@@ -1,9 +1,106 @@
+use crate::types::built_in_types::BuiltInTypes;
+use std::fmt;
+
 #[derive(Debug, Clone)]
 pub enum Value {
+    Null,
+    Bool(bool),
+    Char(char),
     Int(i32),
     Float(f32),
     String(String),
     Array(Vec<Value>),
+    /// A struct value by the full name of its declaration, the fields are in the declaration order
+    Struct {
+        name: String,
+        fields: Vec<(String, Value)>,
+    },
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Char(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            // A float keeps its decimal point -> 2.0
+            Value::Float(value) => write!(f, "{:?}", value),
+            Value::String(value) => write!(f, "{}", value),
+            Value::Array(values) => {
+                write!(f, "[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+
+                write!(f, "]")
+            }
+            Value::Struct { name, fields } => {
+                write!(f, "{} {{", name)?;
+
+                for (index, (field, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, " {}: {}", field, value)?;
+                }
+
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Char(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Self {
+        Value::Array(value)
+    }
+}
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Value::Null
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -17,18 +114,63 @@ pub enum ValueScope {
 pub enum ByteNode {
     Push(Value),
     Pull((ValueScope, usize)),
+    Store((ValueScope, usize)),
     Pop(usize),
     Mov(usize),
+    // Make a array with the given number of values from the top of the stack, in order
+    MakeArray(usize),
+    // Pop a index and a array or str and push the element in that index
+    Index,
+    // Convert the value in the top of the stack to a type, a int is promoted when a float is expected
+    Convert(BuiltInTypes),
+    // Make a struct by its id with the values of its fields from the top of the stack, in order
+    MakeStruct(usize),
+    // Pop a struct and push the value of a field
+    GetField(String),
+    // Pop a value and a struct and push the struct with the value in a field
+    SetField(String),
+    // Jump to a instruction index of the current scope
+    Jump(usize),
+    // Pop the top of the stack and jump if it's false or null
+    JumpIfFalse(usize),
     Add,
     Sub,
     Mul,
     Div,
+    Mod,
+    // Negate the number in the top of the stack
+    Neg,
+    // Compare the two values in the top of the stack and push a bool
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Not,
+    // Call a function by its id with the given number of arguments from the top of the stack
+    Call((usize, usize)),
+    // Call the method of the struct of the first argument, it's the call of the function struct::method
+    CallMethod((String, usize)),
+    // Call a host function by its native id, its value is pushed in the top of the stack
+    CallNative((usize, usize)),
+    // Return from the current function with the value in the top of its stack
+    Ret,
 }
 
 #[derive(Debug, Clone)]
 pub enum Scope {
     Global(Vec<ByteNode>),
-    Function(Vec<ByteNode>),
+    Function {
+        name: String,
+        params: usize,
+        code: Vec<ByteNode>,
+    },
+    // A struct declaration, the runtime uses its field types to check the values given to them
+    Struct {
+        name: String,
+        fields: Vec<(String, BuiltInTypes)>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,2997 @@
+use crate::backend::{bytecode, operations};
+use crate::{handlers, types};
+use std::cmp::Ordering;
+
+/// A variable known by the compiler and the stack slot where it lives
+#[derive(Debug, Clone)]
+struct Variable {
+    name: String,
+    scope: bytecode::ValueScope,
+    index: usize,
+    r#type: types::built_in_types::BuiltInTypes,
+    /// Declared with "let mut", a immutable variable can only be assigned when it has no value
+    mutable: bool,
+    /// If the variable was given a value in the paths that reach the code being compiled
+    initialized: Initialization,
+}
+
+/// If a variable has a value, it depends on the paths taken to reach the code
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Initialization {
+    /// No path gives it a value
+    Unassigned,
+    /// Only some paths give it a value
+    Maybe,
+    /// Every path gives it a value
+    Assigned,
+}
+
+impl Initialization {
+    /// The initialization after two paths join
+    fn join(self, other: Initialization) -> Initialization {
+        match self == other {
+            true => self,
+            false => Initialization::Maybe,
+        }
+    }
+}
+
+/// The state of the function being compiled
+#[derive(Debug, Clone)]
+struct FunctionContext {
+    /// Full name of the function, used in error messages
+    name: String,
+    params: Vec<(String, types::built_in_types::BuiltInTypes)>,
+    returns: types::built_in_types::BuiltInTypes,
+    /// Block scopes of the function, the innermost is the last one
+    blocks: Vec<Vec<Variable>>,
+}
+
+/// The loop being compiled, break and continue jumps are patched when its end is known
+#[derive(Debug, Clone)]
+struct LoopContext {
+    /// Number of variables alive when the loop body starts
+    depth: usize,
+    breaks: Vec<usize>,
+    continues: Vec<usize>,
+    /// Initialization of the variables in each reachable break
+    exits: Vec<Vec<Initialization>>,
+    /// Initialization of the variables in each reachable continue
+    repeats: Vec<Vec<Initialization>>,
+}
+
+impl LoopContext {
+    fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            breaks: vec![],
+            continues: vec![],
+            exits: vec![],
+            repeats: vec![],
+        }
+    }
+}
+
+/// A function known by the compiler, its index in the function list is the function id
+#[derive(Debug, Clone)]
+struct Function {
+    name: String,
+    params: Vec<types::built_in_types::BuiltInTypes>,
+    returns: types::built_in_types::BuiltInTypes,
+}
+
+/// A struct known by the compiler, its index in the struct list is the struct id
+#[derive(Debug, Clone)]
+struct Struct {
+    name: String,
+    fields: Vec<(String, types::built_in_types::BuiltInTypes)>,
+}
+
+/// A name brought by a use statement, it stands for the last segment of the path
+#[derive(Debug, Clone)]
+struct Import {
+    name: String,
+    path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Compiler {
+    pub errors: Vec<handlers::error_handler::Error>,
+    /// Block scopes of the global code, the module scope is the first one
+    globals: Vec<Vec<Variable>>,
+    /// The declared functions, the index is the function id
+    functions: Vec<Function>,
+    /// The functions registered by the host, the index is the native id
+    natives: Vec<Function>,
+    /// The declared structs, the index is the struct id
+    structs: Vec<Struct>,
+    /// The methods that take a "self", named with their struct -> struct::method
+    methods: Vec<Function>,
+    /// Values of the constants and of the host, they are pushed where they are used
+    constants: Vec<(String, bytecode::Value)>,
+    /// Names brought by use statements
+    imports: Vec<Import>,
+    /// Path of the module being compiled, its items are named with it -> module::item
+    namespace: Vec<String>,
+    /// If each namespace declared inside the module being compiled is public
+    public_namespaces: Vec<bool>,
+    /// Name of the module being compiled, empty for sources that aren't imported
+    module: String,
+    /// Items that only their own module can use -> (name, module)
+    private: Vec<(String, String)>,
+    /// The function being compiled, none when compiling global code
+    function: Option<FunctionContext>,
+    /// Loops being compiled, the innermost is the last one
+    loops: Vec<LoopContext>,
+    /// If the code being compiled can't be reached, it's after a return, a break or a continue
+    diverges: bool,
+    /// The initialization of the module variables when the global code first calls a script function
+    /// Any function can run from then, even the ones declared later
+    first_call: Option<Vec<Initialization>>,
+    /// Number of functions, structs and module variables before the source being compiled
+    /// A source can only clash with its own declarations -> (functions, structs, globals)
+    known_before: (usize, usize, usize),
+    /// Index of the first constant of the scope being compiled, a constant can only be declared once in it
+    scope_constants: usize,
+    /// Position of the statement being compiled, used to report errors
+    position: types::others::Position,
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Self {
+            errors: vec![],
+            globals: vec![vec![]],
+            functions: vec![],
+            natives: vec![],
+            structs: vec![],
+            methods: vec![],
+            constants: vec![],
+            imports: vec![],
+            namespace: vec![],
+            public_namespaces: vec![],
+            module: String::new(),
+            private: vec![],
+            function: None,
+            loops: vec![],
+            diverges: false,
+            first_call: None,
+            known_before: (0, 0, 0),
+            scope_constants: 0,
+            position: types::others::Position::new(0, 0),
+        }
+    }
+
+    /// Insert a compiler error at the position of the current statement
+    fn error(&mut self, content: &str, code: handlers::error_handler::ErrorCode) {
+        let error = handlers::error_handler::Error::script_error(
+            content,
+            code,
+            &self.position,
+            handlers::message_handler::Issuer::Compiler,
+        );
+
+        self.errors.insert(self.errors.len(), error);
+    }
+
+    /// Return the block scopes of the current frame
+    fn blocks(&mut self) -> &mut Vec<Vec<Variable>> {
+        match &mut self.function {
+            Some(function) => &mut function.blocks,
+            None => &mut self.globals,
+        }
+    }
+
+    /// Number of variables alive in the current frame <- also the stack size between statements
+    fn live_variables(&mut self) -> usize {
+        self.blocks().iter().map(|block| block.len()).sum()
+    }
+
+    /// If the code being compiled is the module scope, outside of functions and blocks
+    fn at_module_scope(&self) -> bool {
+        self.function.is_none() && self.globals.len() == 1
+    }
+
+    /// Declare a variable in the innermost block, its slot is the top of the current stack
+    ///
+    /// A variable can shadow the ones of the outer blocks, the parameters and the globals, but
+    /// not one declared before it in the same block.
+    fn declare_variable(
+        &mut self,
+        name: &str,
+        r#type: types::built_in_types::BuiltInTypes,
+        mutable: bool,
+        initialized: bool,
+    ) {
+        let scope = match self.function {
+            Some(_) => bytecode::ValueScope::Local,
+            None => bytecode::ValueScope::Global,
+        };
+        let index = self.live_variables();
+
+        // Variables of the module scope are named with the namespace
+        let (name, known) = match self.at_module_scope() {
+            true => (self.qualified(name), self.known_before.2),
+            false => (name.to_string(), 0),
+        };
+
+        let initialized = match initialized {
+            true => Initialization::Assigned,
+            false => Initialization::Unassigned,
+        };
+
+        let declared = self.blocks().last().is_some_and(|block| {
+            block
+                .iter()
+                .skip(known)
+                .any(|variable| variable.name == name)
+        });
+
+        if declared {
+            self.error(
+                format!("\"{}\" is already declared in this scope.", name).as_str(),
+                handlers::error_handler::ErrorCode::E031,
+            );
+        }
+
+        if let Some(block) = self.blocks().last_mut() {
+            block.push(Variable {
+                name,
+                scope,
+                index,
+                r#type,
+                mutable,
+                initialized,
+            });
+        }
+    }
+
+    /// Find a variable by its name <- locals, then parameters, then globals
+    fn lookup_variable(&self, name: &str) -> Option<Variable> {
+        if let Some(function) = &self.function {
+            for block in function.blocks.iter().rev() {
+                if let Some(variable) = block.iter().rev().find(|v| v.name == name) {
+                    return Some(variable.to_owned());
+                }
+            }
+
+            if let Some(index) = function.params.iter().rposition(|(param, _)| param == name) {
+                return Some(Variable {
+                    name: name.to_string(),
+                    scope: bytecode::ValueScope::Args,
+                    index,
+                    r#type: function.params[index].1.to_owned(),
+                    mutable: true,
+                    initialized: Initialization::Assigned,
+                });
+            }
+        } else {
+            for block in self.globals.iter().skip(1).rev() {
+                if let Some(variable) = block.iter().rev().find(|v| v.name == name) {
+                    return Some(variable.to_owned());
+                }
+            }
+        }
+
+        // A function body only sees the module scope of the global code
+        self.candidates(name).iter().find_map(|candidate| {
+            self.globals[0]
+                .iter()
+                .rev()
+                .find(|v| v.name == *candidate)
+                .cloned()
+        })
+    }
+
+    /// Record a item declared in the current namespace as private, unless it and its namespaces are public
+    fn declare_visibility(&mut self, name: &str, public: bool) {
+        // Sources that aren't imported can't be used by other modules
+        if self.module.is_empty() {
+            return;
+        }
+
+        if !public || self.public_namespaces.contains(&false) {
+            self.private
+                .push((name.to_string(), self.module.to_owned()));
+        }
+    }
+
+    /// If a item is private to another module
+    fn is_private(&self, name: &str) -> bool {
+        self.private
+            .iter()
+            .any(|(item, module)| item == name && *module != self.module)
+    }
+
+    /// Report the use of a item that is private to another module
+    fn check_visibility(&mut self, name: &str) {
+        if self.is_private(name) {
+            self.error(
+                format!("\"{}\" is private to its module.", name).as_str(),
+                handlers::error_handler::ErrorCode::E023,
+            );
+        }
+    }
+
+    /// Name a item declared in the current namespace -> namespace::name
+    fn qualified(&self, name: &str) -> String {
+        match self.namespace.is_empty() {
+            true => name.to_string(),
+            false => format!("{}::{}", self.namespace.join("::"), name),
+        }
+    }
+
+    /// The full names a name can refer to, from the innermost namespace to the imported path
+    fn candidates(&self, name: &str) -> Vec<String> {
+        let mut candidates: Vec<String> = (0..=self.namespace.len())
+            .rev()
+            .map(|depth| match depth {
+                0 => name.to_string(),
+                _ => format!("{}::{}", self.namespace[..depth].join("::"), name),
+            })
+            .collect();
+
+        if let Some(path) = self.imported_path(name) {
+            candidates.push(path);
+        }
+
+        candidates
+    }
+
+    /// Return the slot and the type of a variable declared in the module scope of the global code
+    pub fn global_slot(&self, name: &str) -> Option<(usize, types::built_in_types::BuiltInTypes)> {
+        self.globals[0]
+            .iter()
+            .rev()
+            .find(|variable| variable.name == name)
+            .map(|variable| (variable.index, variable.r#type.to_owned()))
+    }
+
+    /// Declare a variable in the module scope, its value must be pushed in the global stack by the caller
+    /// A value of the host can be of any type
+    pub fn declare_global(&mut self, name: &str) -> usize {
+        let index = self.globals.iter().map(|block| block.len()).sum();
+
+        self.globals[0].push(Variable {
+            name: name.to_string(),
+            scope: bytecode::ValueScope::Global,
+            index,
+            r#type: types::built_in_types::BuiltInTypes::Any,
+            mutable: true,
+            initialized: Initialization::Assigned,
+        });
+
+        index
+    }
+
+    /// Return the id of a declared function
+    pub fn function_id(&self, name: &str) -> Option<usize> {
+        self.lookup_function(name)
+    }
+
+    /// Declare a host function, the runtime must register it with the returned native id
+    pub fn declare_native(
+        &mut self,
+        name: &str,
+        params: Vec<types::built_in_types::BuiltInTypes>,
+        returns: types::built_in_types::BuiltInTypes,
+    ) -> usize {
+        self.natives.push(Function {
+            name: name.to_string(),
+            params,
+            returns,
+        });
+
+        self.natives.len() - 1
+    }
+
+    /// Declare a host value, it's inlined in the code that uses it
+    pub fn declare_constant(&mut self, name: &str, value: bytecode::Value) {
+        self.constants.push((name.to_string(), value));
+    }
+
+    /// Find a constant by its name -> (full name, value)
+    fn resolve_constant(&self, name: &str) -> Option<(String, bytecode::Value)> {
+        self.candidates(name).iter().find_map(|candidate| {
+            self.constants
+                .iter()
+                .rev()
+                .find(|(constant, _)| constant == candidate)
+                .cloned()
+        })
+    }
+
+    /// Find the value of a constant by its name
+    fn lookup_constant(&self, name: &str) -> Option<bytecode::Value> {
+        self.resolve_constant(name).map(|(_, value)| value)
+    }
+
+    /// Find a native id by its name
+    fn lookup_native(&self, name: &str) -> Option<usize> {
+        self.natives.iter().rposition(|native| native.name == name)
+    }
+
+    /// Find a function or a native by its name -> (id, function, is native)
+    fn lookup_callable(&self, name: &str) -> Option<(usize, Function, bool)> {
+        self.candidates(name).iter().find_map(|candidate| {
+            // Script functions hide the natives with the same name
+            match self.lookup_function(candidate) {
+                Some(id) => Some((id, self.functions[id].to_owned(), false)),
+                None => self
+                    .lookup_native(candidate)
+                    .map(|id| (id, self.natives[id].to_owned(), true)),
+            }
+        })
+    }
+
+    /// Find a struct id by its name
+    fn lookup_struct(&self, name: &str) -> Option<usize> {
+        self.candidates(name).iter().find_map(|candidate| {
+            self.structs
+                .iter()
+                .rposition(|declaration| declaration.name == *candidate)
+        })
+    }
+
+    /// Give the full name of the struct a type refers to, or nothing if the struct isn't declared
+    fn resolve_type(
+        &self,
+        r#type: &types::built_in_types::BuiltInTypes,
+    ) -> Option<types::built_in_types::BuiltInTypes> {
+        match r#type {
+            types::built_in_types::BuiltInTypes::Struct(name) => {
+                let id = self.lookup_struct(name)?;
+
+                Some(types::built_in_types::BuiltInTypes::Struct(
+                    self.structs[id].name.to_owned(),
+                ))
+            }
+            r#type => Some(r#type.to_owned()),
+        }
+    }
+
+    /// Resolve a written type, a undefined struct is reported and taken as "any"
+    fn written_type(
+        &mut self,
+        r#type: &types::built_in_types::BuiltInTypes,
+    ) -> types::built_in_types::BuiltInTypes {
+        match self.resolve_type(r#type) {
+            Some(r#type) => r#type,
+            None => {
+                self.error(
+                    format!("type \"{}\" is not defined.", r#type).as_str(),
+                    handlers::error_handler::ErrorCode::E025,
+                );
+                types::built_in_types::BuiltInTypes::Any
+            }
+        }
+    }
+
+    /// Resolve the written type of something that holds a value, it can't be "void"
+    fn declared_type(
+        &mut self,
+        r#type: &types::built_in_types::BuiltInTypes,
+        what: &str,
+    ) -> types::built_in_types::BuiltInTypes {
+        match self.written_type(r#type) {
+            types::built_in_types::BuiltInTypes::Void => {
+                self.error(
+                    format!("{} can't be void.", what).as_str(),
+                    handlers::error_handler::ErrorCode::E030,
+                );
+                types::built_in_types::BuiltInTypes::Any
+            }
+            r#type => r#type,
+        }
+    }
+
+    /// Declare the types of a function, its return type can be "void"
+    fn function_types(
+        &mut self,
+        name: String,
+        params: &[types::parse_nodes::FuncParam],
+        returns: &types::built_in_types::BuiltInTypes,
+    ) -> Function {
+        for (index, param) in params.iter().enumerate() {
+            if params[..index].iter().any(|other| other.name == param.name) {
+                self.error(
+                    format!(
+                        "parameter \"{}\" of \"{}\" is declared more than once.",
+                        param.name, name
+                    )
+                    .as_str(),
+                    handlers::error_handler::ErrorCode::E031,
+                );
+            }
+        }
+
+        let params = params
+            .iter()
+            .map(|param| {
+                self.declared_type(
+                    &param.r#type,
+                    format!("parameter \"{}\" of \"{}\"", param.name, name).as_str(),
+                )
+            })
+            .collect();
+        let returns = self.written_type(returns);
+
+        Function {
+            name,
+            params,
+            returns,
+        }
+    }
+
+    /// Report a field that no struct declares, the struct of a value is only known when it runs
+    fn check_field(&mut self, field: &str) -> bool {
+        let declared = self
+            .structs
+            .iter()
+            .any(|declaration| declaration.fields.iter().any(|(name, _)| name == field));
+
+        if !declared {
+            self.error(
+                format!("no struct has a field \"{}\".", field).as_str(),
+                handlers::error_handler::ErrorCode::E026,
+            );
+        }
+
+        declared
+    }
+
+    /// The type of a field of a value, a value of type "any" can be any struct with the field
+    fn field_type(
+        &mut self,
+        r#type: &types::built_in_types::BuiltInTypes,
+        field: &str,
+    ) -> Option<types::built_in_types::BuiltInTypes> {
+        match r#type {
+            types::built_in_types::BuiltInTypes::Any => self
+                .check_field(field)
+                .then_some(types::built_in_types::BuiltInTypes::Any),
+            types::built_in_types::BuiltInTypes::Struct(name) => {
+                let found = self
+                    .structs
+                    .iter()
+                    .rev()
+                    .find(|declaration| declaration.name == *name)
+                    .and_then(|declaration| {
+                        declaration
+                            .fields
+                            .iter()
+                            .find(|(declared, _)| declared == field)
+                    })
+                    .map(|(_, r#type)| r#type.to_owned());
+
+                if found.is_none() {
+                    self.error(
+                        format!("struct \"{}\" has no field \"{}\".", name, field).as_str(),
+                        handlers::error_handler::ErrorCode::E026,
+                    );
+                }
+
+                found
+            }
+            r#type => {
+                self.error(
+                    format!("{} has no fields.", r#type).as_str(),
+                    handlers::error_handler::ErrorCode::E029,
+                );
+                None
+            }
+        }
+    }
+
+    /// Expand a name that starts with a imported name into its full path -> io::println <- std::io::println
+    fn imported_path(&self, name: &str) -> Option<String> {
+        let (first, rest) = match name.split_once("::") {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (name, None),
+        };
+
+        self.imports
+            .iter()
+            .rev()
+            .find(|import| import.name == first)
+            .map(|import| match rest {
+                Some(rest) => format!("{}::{}", import.path, rest),
+                None => import.path.to_owned(),
+            })
+    }
+
+    /// If there is a item with the path as its name or as its namespace
+    pub fn is_known_path(&self, path: &str) -> bool {
+        let namespace = format!("{}::", path);
+
+        self.functions
+            .iter()
+            .chain(self.natives.iter())
+            .map(|function| &function.name)
+            .chain(self.structs.iter().map(|declaration| &declaration.name))
+            .chain(self.constants.iter().map(|(name, _)| name))
+            .chain(self.globals[0].iter().map(|variable| &variable.name))
+            .any(|name| name == path || name.starts_with(&namespace))
+    }
+
+    /// Bring the names of the use statements of a module before its items are declared
+    fn declare_imports(&mut self, body: &[types::parse_nodes::Statement]) {
+        for statement in body {
+            if let types::parse_nodes::Statement::Use { path, .. } = statement {
+                // A unknown path is reported when the statement is compiled
+                if self.is_known_path(path) {
+                    self.import(path);
+                }
+            }
+        }
+    }
+
+    /// Bring a path by its last name -> use std::io <- io
+    fn import(&mut self, path: &str) {
+        let name = path.rsplit("::").next().unwrap_or(path);
+
+        if !self
+            .imports
+            .iter()
+            .any(|import| import.name == name && import.path == path)
+        {
+            self.imports.push(Import {
+                name: name.to_string(),
+                path: path.to_string(),
+            });
+        }
+    }
+
+    /// Register every struct declared in a body so they can be used before their declaration
+    fn declare_structs(&mut self, body: &[types::parse_nodes::Statement]) {
+        for statement in body {
+            match statement {
+                // The field types are resolved when every struct is known
+                types::parse_nodes::Statement::StructDeclaration {
+                    start,
+                    name,
+                    fields,
+                    public,
+                } => {
+                    self.position = start.to_owned();
+
+                    let name = self.qualified(name);
+
+                    if self.structs[self.known_before.1..]
+                        .iter()
+                        .any(|declaration| declaration.name == name)
+                    {
+                        self.error(
+                            format!("struct \"{}\" is already declared.", name).as_str(),
+                            handlers::error_handler::ErrorCode::E031,
+                        );
+                    }
+
+                    self.declare_visibility(&name, *public);
+                    self.structs.push(Struct {
+                        name,
+                        fields: fields
+                            .iter()
+                            .map(|field| (field.name.to_owned(), field.r#type.to_owned()))
+                            .collect(),
+                    });
+                }
+                types::parse_nodes::Statement::Namespace {
+                    name, body, public, ..
+                } => {
+                    self.namespace.push(name.to_owned());
+                    self.public_namespaces.push(*public);
+                    self.declare_structs(body);
+                    self.public_namespaces.pop();
+                    self.namespace.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Report a function that the source being compiled already declares
+    fn check_redeclared(&mut self, name: &str) {
+        if self.functions[self.known_before.0..]
+            .iter()
+            .any(|function| function.name == name)
+        {
+            self.error(
+                format!("function \"{}\" is already declared.", name).as_str(),
+                handlers::error_handler::ErrorCode::E031,
+            );
+        }
+    }
+
+    /// Register every function and method declared in a body so calls can reference functions declared later
+    fn declare_functions(&mut self, body: &[types::parse_nodes::Statement]) {
+        for statement in body {
+            match statement {
+                types::parse_nodes::Statement::FuctionDeclaration {
+                    start,
+                    name,
+                    r#type,
+                    params,
+                    public,
+                    ..
+                } => {
+                    self.position = start.to_owned();
+
+                    let name = self.qualified(name);
+                    let function = self.function_types(name, params, r#type);
+
+                    self.check_redeclared(&function.name);
+                    self.declare_visibility(&function.name, *public);
+                    self.functions.push(function);
+                }
+                // The methods are named with the full name of their struct -> struct::method
+                types::parse_nodes::Statement::Impl {
+                    start,
+                    name,
+                    methods,
+                } => {
+                    self.position = start.to_owned();
+
+                    let owner = match self.lookup_struct(name) {
+                        Some(id) => self.structs[id].name.to_owned(),
+                        None => {
+                            self.error(
+                                format!("struct \"{}\" is not defined.", name).as_str(),
+                                handlers::error_handler::ErrorCode::E025,
+                            );
+                            continue;
+                        }
+                    };
+
+                    for method in methods.iter() {
+                        if let types::parse_nodes::Statement::FuctionDeclaration {
+                            start,
+                            name,
+                            r#type,
+                            params,
+                            public,
+                            ..
+                        } = method
+                        {
+                            self.position = start.to_owned();
+
+                            let name = format!("{}::{}", owner, name);
+                            let function = self.function_types(name, params, r#type);
+
+                            self.check_redeclared(&function.name);
+
+                            self.declare_visibility(&function.name, *public);
+
+                            // Only a method that takes a "self" can be called on a value
+                            if params.first().is_some_and(|param| param.name == "self") {
+                                self.methods.push(function.to_owned());
+                            }
+
+                            self.functions.push(function);
+                        }
+                    }
+                }
+                types::parse_nodes::Statement::Namespace {
+                    name, body, public, ..
+                } => {
+                    self.namespace.push(name.to_owned());
+                    self.public_namespaces.push(*public);
+                    self.declare_functions(body);
+                    self.public_namespaces.pop();
+                    self.namespace.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolve the field types of the structs declared in a body to the full names of their structs
+    fn define_structs(&mut self, body: &[types::parse_nodes::Statement]) {
+        for statement in body {
+            match statement {
+                types::parse_nodes::Statement::StructDeclaration {
+                    start,
+                    name,
+                    fields,
+                    ..
+                } => {
+                    self.position = start.to_owned();
+
+                    let name = self.qualified(name);
+                    let id = match self.structs.iter().rposition(|item| item.name == name) {
+                        Some(id) => id,
+                        None => continue,
+                    };
+
+                    for (index, field) in fields.iter().enumerate() {
+                        if fields[..index].iter().any(|other| other.name == field.name) {
+                            self.error(
+                                format!(
+                                    "struct \"{}\" declares the field \"{}\" more than once.",
+                                    name, field.name
+                                )
+                                .as_str(),
+                                handlers::error_handler::ErrorCode::E027,
+                            );
+                        }
+
+                        self.structs[id].fields[index].1 = self.declared_type(
+                            &field.r#type,
+                            format!("field \"{}\" of \"{}\"", field.name, name).as_str(),
+                        );
+                    }
+                }
+                types::parse_nodes::Statement::Namespace { name, body, .. } => {
+                    self.namespace.push(name.to_owned());
+                    self.define_structs(body);
+                    self.namespace.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Compile a module statement and return its bytecode
+    pub fn compile(&mut self, ast: &types::parse_nodes::Statement) -> bytecode::ByteCode {
+        let mut global: Vec<bytecode::ByteNode> = vec![];
+        let mut scopes: Vec<bytecode::Scope> = vec![];
+
+        let body = match ast {
+            types::parse_nodes::Statement::Module { start, body } => {
+                self.position = start.to_owned();
+                body
+            }
+            _ => {
+                self.error(
+                    "expected a module to compile.",
+                    handlers::error_handler::ErrorCode::E003,
+                );
+                return bytecode::ByteCode { code: vec![] };
+            }
+        };
+
+        self.known_before = (
+            self.functions.len(),
+            self.structs.len(),
+            self.globals[0].len(),
+        );
+        self.first_call = None;
+        self.scope_constants = self.constants.len();
+
+        self.declare_imports(body);
+        self.declare_structs(body);
+        self.declare_functions(body);
+        self.define_structs(body);
+        self.compile_items(body, &mut global, &mut scopes);
+
+        let mut code = vec![bytecode::Scope::Global(global)];
+        code.append(&mut scopes);
+
+        bytecode::ByteCode { code }
+    }
+
+    /// Compile the items of a module or a namespace, functions and structs get their own scope
+    fn compile_items(
+        &mut self,
+        body: &[types::parse_nodes::Statement],
+        global: &mut Vec<bytecode::ByteNode>,
+        scopes: &mut Vec<bytecode::Scope>,
+    ) {
+        for statement in body.iter() {
+            match statement {
+                types::parse_nodes::Statement::FuctionDeclaration { .. } => {
+                    scopes.push(self.compile_function(statement, None));
+                }
+                // A undefined struct is reported when its methods are declared
+                types::parse_nodes::Statement::Impl { name, methods, .. } => {
+                    let owner = match self.lookup_struct(name) {
+                        Some(id) => self.structs[id].name.to_owned(),
+                        None => continue,
+                    };
+
+                    for method in methods.iter() {
+                        scopes.push(self.compile_function(method, Some(&owner)));
+                    }
+                }
+                types::parse_nodes::Statement::StructDeclaration { name, .. } => {
+                    let name = self.qualified(name);
+
+                    if let Some(declaration) =
+                        self.structs.iter().rev().find(|item| item.name == name)
+                    {
+                        scopes.push(bytecode::Scope::Struct {
+                            name,
+                            fields: declaration.fields.to_owned(),
+                        });
+                    }
+                }
+                // The items of a namespace are named with it -> name::item
+                types::parse_nodes::Statement::Namespace {
+                    name, body, public, ..
+                } => {
+                    let path = self.qualified(name);
+                    self.declare_visibility(&path, *public);
+
+                    self.namespace.push(name.to_owned());
+                    self.public_namespaces.push(*public);
+                    self.compile_items(body, global, scopes);
+                    self.public_namespaces.pop();
+                    self.namespace.pop();
+                }
+                _ => self.compile_statement(statement, global),
+            }
+        }
+    }
+
+    /// Compile a imported module, its functions and globals are named with the module path
+    pub fn compile_module(
+        &mut self,
+        ast: &types::parse_nodes::Statement,
+        name: &str,
+    ) -> bytecode::ByteCode {
+        // The module doesn't see the imports of who imports it
+        let namespace = std::mem::replace(
+            &mut self.namespace,
+            name.split("::").map(String::from).collect(),
+        );
+        let imports = std::mem::take(&mut self.imports);
+        let module = std::mem::replace(&mut self.module, name.to_string());
+
+        let bytecode = self.compile(ast);
+
+        self.namespace = namespace;
+        self.imports = imports;
+        self.module = module;
+
+        bytecode
+    }
+
+    /// Find a function id by its name
+    fn lookup_function(&self, name: &str) -> Option<usize> {
+        self.functions
+            .iter()
+            .rposition(|function| function.name == name)
+    }
+
+    /// Compile a function declaration into a function scope, a method is named with its struct
+    fn compile_function(
+        &mut self,
+        statement: &types::parse_nodes::Statement,
+        owner: Option<&str>,
+    ) -> bytecode::Scope {
+        let mut code: Vec<bytecode::ByteNode> = vec![];
+        let mut function_name = String::new();
+        let mut function_params: usize = 0;
+
+        if let types::parse_nodes::Statement::FuctionDeclaration {
+            start,
+            name,
+            params,
+            body,
+            ..
+        } = statement
+        {
+            self.position = start.to_owned();
+
+            function_name = match owner {
+                Some(owner) => format!("{}::{}", owner, name),
+                None => self.qualified(name),
+            };
+            function_params = params.len();
+
+            // The types were resolved when the function was declared
+            let function = match self.lookup_function(&function_name) {
+                Some(id) => self.functions[id].to_owned(),
+                None => Function {
+                    name: function_name.to_owned(),
+                    params: vec![types::built_in_types::BuiltInTypes::Any; params.len()],
+                    returns: types::built_in_types::BuiltInTypes::Any,
+                },
+            };
+
+            // The arguments are converted to the parameter types when the function starts
+            for (index, r#type) in function.params.iter().enumerate() {
+                if *r#type != types::built_in_types::BuiltInTypes::Any {
+                    code.push(bytecode::ByteNode::Pull((
+                        bytecode::ValueScope::Args,
+                        index,
+                    )));
+                    code.push(bytecode::ByteNode::Convert(r#type.to_owned()));
+                    code.push(bytecode::ByteNode::Store((
+                        bytecode::ValueScope::Args,
+                        index,
+                    )));
+                }
+            }
+
+            self.function = Some(FunctionContext {
+                name: function.name,
+                params: params
+                    .iter()
+                    .map(|param| param.name.to_owned())
+                    .zip(function.params)
+                    .collect(),
+                returns: function.returns,
+                blocks: vec![vec![]],
+            });
+
+            let constants = self.constants.len();
+            let scope_constants = std::mem::replace(&mut self.scope_constants, constants);
+            self.diverges = false;
+
+            for statement in body.iter() {
+                self.compile_statement(statement, &mut code);
+            }
+
+            self.constants.truncate(constants);
+            self.scope_constants = scope_constants;
+
+            // Only a function that returns nothing can reach its end
+            let returns = self
+                .function
+                .as_ref()
+                .map(|function| function.returns.to_owned())
+                .unwrap_or(types::built_in_types::BuiltInTypes::Any);
+
+            if !self.diverges
+                && !matches!(
+                    returns,
+                    types::built_in_types::BuiltInTypes::Any
+                        | types::built_in_types::BuiltInTypes::Void
+                        | types::built_in_types::BuiltInTypes::Null
+                )
+            {
+                self.position = start.to_owned();
+                self.error(
+                    format!(
+                        "\"{}\" returns {} but its end can be reached without a return.",
+                        function_name, returns
+                    )
+                    .as_str(),
+                    handlers::error_handler::ErrorCode::E034,
+                );
+            }
+
+            // A function without a return statement in the end returns null
+            code.push(bytecode::ByteNode::Push(bytecode::Value::Null));
+            code.push(bytecode::ByteNode::Ret);
+
+            self.function = None;
+            self.diverges = false;
+        }
+
+        bytecode::Scope::Function {
+            name: function_name,
+            params: function_params,
+            code,
+        }
+    }
+
+    /// Compile the statements of a block, the variables declared inside are dropped at the end
+    fn compile_block(
+        &mut self,
+        body: &[types::parse_nodes::Statement],
+        code: &mut Vec<bytecode::ByteNode>,
+    ) {
+        self.blocks().push(vec![]);
+        let constants = self.constants.len();
+        let scope_constants = std::mem::replace(&mut self.scope_constants, constants);
+
+        for statement in body {
+            self.compile_statement(statement, code);
+        }
+
+        // The constants of the block are only known inside it
+        self.constants.truncate(constants);
+        self.scope_constants = scope_constants;
+
+        // Pop the block variables from the top of the stack
+        if let Some(block) = self.blocks().pop() {
+            for variable in block.iter().rev() {
+                code.push(bytecode::ByteNode::Pop(variable.index));
+            }
+        }
+    }
+
+    /// Emit the pops of the variables declared inside the innermost loop and a jump to be patched
+    fn compile_loop_exit(&mut self, code: &mut Vec<bytecode::ByteNode>) -> Option<usize> {
+        let depth = self.loops.last()?.depth;
+
+        for index in (depth..self.live_variables()).rev() {
+            code.push(bytecode::ByteNode::Pop(index));
+        }
+
+        code.push(bytecode::ByteNode::Jump(0));
+
+        Some(code.len() - 1)
+    }
+
+    /// Compile a loop body, return the jumps of its break and continue statements
+    fn compile_loop_body(
+        &mut self,
+        body: Option<&[types::parse_nodes::Statement]>,
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> LoopContext {
+        let depth = self.live_variables();
+
+        self.loops.push(LoopContext::new(depth));
+
+        if let Some(body) = body {
+            self.compile_block(body, code);
+        }
+
+        self.loops.pop().unwrap_or(LoopContext::new(depth))
+    }
+
+    /// If a variable lives in the frame being compiled, the global code or the current function
+    fn in_frame(&self, variable: &Variable) -> bool {
+        matches!(
+            (&variable.scope, &self.function),
+            (bytecode::ValueScope::Local, Some(_)) | (bytecode::ValueScope::Global, None)
+        )
+    }
+
+    /// Record that a variable of the current frame was given a value
+    fn initialize(&mut self, variable: &Variable) {
+        if !self.in_frame(variable) {
+            return;
+        }
+
+        if let Some(declared) = self
+            .blocks()
+            .iter_mut()
+            .flatten()
+            .find(|declared| declared.index == variable.index)
+        {
+            declared.initialized = Initialization::Assigned;
+        }
+    }
+
+    /// The initialization of the variables of the current frame, in their declaration order
+    fn initializations(&mut self) -> Vec<Initialization> {
+        self.blocks()
+            .iter()
+            .flatten()
+            .map(|variable| variable.initialized)
+            .collect()
+    }
+
+    /// Replace the initialization of the variables of the current frame
+    fn set_initializations(&mut self, initializations: &[Initialization]) {
+        for (variable, initialized) in self
+            .blocks()
+            .iter_mut()
+            .flatten()
+            .zip(initializations.iter())
+        {
+            variable.initialized = *initialized;
+        }
+    }
+
+    /// Join the initializations of the paths that reach the same code, none if no path reaches it
+    ///
+    /// The variables declared inside the paths are dropped, only the ones of every path are kept
+    fn join_states(
+        states: impl IntoIterator<Item = Vec<Initialization>>,
+    ) -> Option<Vec<Initialization>> {
+        states.into_iter().reduce(|first, second| {
+            first
+                .into_iter()
+                .zip(second)
+                .map(|(first, second)| first.join(second))
+                .collect()
+        })
+    }
+
+    /// Continue after a loop with the initializations of its exits, it diverges without any
+    fn leave_loop(&mut self, before: &[Initialization], exits: Vec<Vec<Initialization>>) {
+        match Compiler::join_states(exits) {
+            Some(after) => {
+                self.set_initializations(&after);
+                self.diverges = false;
+            }
+            None => {
+                self.set_initializations(before);
+                self.diverges = true;
+            }
+        }
+    }
+
+    /// Report the immutable variables that a iteration initializes when the next one starts
+    ///
+    /// The repeats are the initializations where the loop goes back to its start, a variable
+    /// without a value before the loop would be initialized again by the next iteration.
+    fn check_repeats(&mut self, before: &[Initialization], repeats: &[Vec<Initialization>]) {
+        let names: Vec<String> = self
+            .blocks()
+            .iter()
+            .flatten()
+            .zip(before.iter())
+            .enumerate()
+            .filter(|(index, (variable, initialized))| {
+                !variable.mutable
+                    && **initialized == Initialization::Unassigned
+                    && repeats.iter().any(|repeat| {
+                        repeat
+                            .get(*index)
+                            .is_some_and(|repeat| *repeat != Initialization::Unassigned)
+                    })
+            })
+            .map(|(_, (variable, _))| variable.name.to_owned())
+            .collect();
+
+        for name in names {
+            self.error(
+                format!(
+                    "\"{}\" is immutable, a loop can't initialize it in more than one iteration. Declare it with \"let mut\" to change it.",
+                    name
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E032,
+            );
+        }
+    }
+
+    /// Join the initializations of two paths, a path that can't reach the end doesn't count
+    fn join_paths(
+        &mut self,
+        first: (Vec<Initialization>, bool),
+        second: (Vec<Initialization>, bool),
+    ) {
+        let initializations: Vec<Initialization> = match (first, second) {
+            ((_, true), (second, false)) => second,
+            ((first, false), (_, true)) => first,
+            ((first, _), (second, _)) => first
+                .into_iter()
+                .zip(second)
+                .map(|(first, second)| first.join(second))
+                .collect(),
+        };
+
+        self.set_initializations(&initializations);
+    }
+
+    /// Record the initialization of the module variables if it's the first call of the global code
+    fn record_call(&mut self) {
+        if self.function.is_none() && self.first_call.is_none() {
+            self.first_call = Some(
+                self.globals[0]
+                    .iter()
+                    .map(|variable| variable.initialized)
+                    .collect(),
+            );
+        }
+    }
+
+    /// Report the read of a variable that may not have a value
+    ///
+    /// A function can run when it's called before its declaration, it only reads the module
+    /// variables that have a value when it's declared and when the global code first calls a function.
+    fn check_initialized(&mut self, variable: &Variable) {
+        if !self.in_frame(variable) {
+            if !matches!(variable.scope, bytecode::ValueScope::Global) {
+                return;
+            }
+
+            // The variables are only given values after they're declared
+            let position = self.globals[0]
+                .iter()
+                .position(|declared| declared.index == variable.index);
+            let initialized = match (&self.first_call, position) {
+                (Some(first_call), Some(position)) => first_call
+                    .get(position)
+                    .copied()
+                    .unwrap_or(Initialization::Unassigned),
+                _ => variable.initialized,
+            };
+
+            if initialized != Initialization::Assigned {
+                let function = self
+                    .function
+                    .as_ref()
+                    .map(|function| function.name.to_owned())
+                    .unwrap_or_default();
+
+                self.error(
+                    format!(
+                        "\"{}\" may not have a value when \"{}\" runs, a function can only read the module variables that have one before its declaration and before the first call.",
+                        variable.name, function
+                    )
+                    .as_str(),
+                    handlers::error_handler::ErrorCode::E033,
+                );
+            }
+
+            return;
+        }
+
+        let message = match variable.initialized {
+            Initialization::Assigned => return,
+            Initialization::Maybe => format!(
+                "\"{}\" may be used before it has a value, not every path gives it one.",
+                variable.name
+            ),
+            Initialization::Unassigned => {
+                format!("\"{}\" is used before it has a value.", variable.name)
+            }
+        };
+
+        self.error(message.as_str(), handlers::error_handler::ErrorCode::E033);
+    }
+
+    /// Find the variable of a assignment, constants, undefined names and immutable variables are reported
+    ///
+    /// A immutable variable declared without a value can be initialized once by a plain "=", only
+    /// from the frame where it's declared.
+    fn lookup_assigned(&mut self, name: &str, initializes: bool) -> Option<Variable> {
+        let variable = match self.lookup_variable(name) {
+            Some(variable) => variable,
+            None if self.lookup_constant(name).is_some() => {
+                self.error(
+                    format!("\"{}\" is a constant, it can't be assigned.", name).as_str(),
+                    handlers::error_handler::ErrorCode::E024,
+                );
+                return None;
+            }
+            None => {
+                self.error(
+                    format!("\"{}\" is not defined.", name).as_str(),
+                    handlers::error_handler::ErrorCode::E004,
+                );
+                return None;
+            }
+        };
+
+        self.check_visibility(&variable.name);
+
+        if !variable.mutable {
+            let message = match variable.initialized {
+                Initialization::Assigned => {
+                    Some(format!("\"{}\" is immutable and it already has a value.", name))
+                }
+                Initialization::Maybe => Some(format!(
+                    "\"{}\" is immutable and it may already have a value.",
+                    name
+                )),
+                _ if !initializes || !self.in_frame(&variable) => Some(format!(
+                    "\"{}\" is immutable, it can only be initialized with \"=\" where it's declared.",
+                    name
+                )),
+                _ => None,
+            };
+
+            if let Some(message) = message {
+                self.error(
+                    format!("{} Declare it with \"let mut\" to change it.", message).as_str(),
+                    handlers::error_handler::ErrorCode::E032,
+                );
+                return None;
+            }
+        }
+
+        // A compound assignment or a field assignment reads the current value
+        if !initializes {
+            self.check_initialized(&variable);
+        }
+
+        Some(variable)
+    }
+
+    /// The operation of a compound assignment on the current value -> x += 1 <- x = x + 1
+    /// Nothing inside for a plain "=", and nothing at all for a unsupported operator
+    fn assignment_operation(
+        &mut self,
+        operator: &types::tokens::TokenType,
+    ) -> Option<Option<bytecode::ByteNode>> {
+        match operator {
+            types::tokens::TokenType::OpAssign => Some(None),
+            types::tokens::TokenType::OpAssignPlus => Some(Some(bytecode::ByteNode::Add)),
+            types::tokens::TokenType::OpAssignMinus => Some(Some(bytecode::ByteNode::Sub)),
+            types::tokens::TokenType::OpAssignMultiply => Some(Some(bytecode::ByteNode::Mul)),
+            types::tokens::TokenType::OpAssignDivision => Some(Some(bytecode::ByteNode::Div)),
+            types::tokens::TokenType::OpAssignRest => Some(Some(bytecode::ByteNode::Mod)),
+            _ => {
+                self.error(
+                    format!(
+                        "{:?} operator is not supported by the code generator.",
+                        operator
+                    )
+                    .as_str(),
+                    handlers::error_handler::ErrorCode::E003,
+                );
+                None
+            }
+        }
+    }
+
+    fn compile_statement(
+        &mut self,
+        statement: &types::parse_nodes::Statement,
+        code: &mut Vec<bytecode::ByteNode>,
+    ) {
+        match statement {
+            types::parse_nodes::Statement::LetDeclaration {
+                start,
+                name,
+                kind,
+                r#type,
+                value,
+                public,
+            } => {
+                self.position = start.to_owned();
+
+                let declared = r#type.as_ref().map(|r#type| {
+                    self.declared_type(r#type, format!("variable \"{}\"", name).as_str())
+                });
+
+                // The value stays in the stack, it's the variable slot
+                let found = match value {
+                    Some(value) => self.compile_value(value, code),
+                    None => {
+                        code.push(bytecode::ByteNode::Push(bytecode::Value::Null));
+                        types::built_in_types::BuiltInTypes::Any
+                    }
+                };
+
+                // Without a explicity type, the type comes from the value <- a null can be anything later
+                let r#type = match declared {
+                    Some(r#type) if value.is_some() => {
+                        self.coerce(&r#type, &found, code, || {
+                            format!("\"{}\" is {} but the value is {}.", name, r#type, found)
+                        });
+                        r#type
+                    }
+                    Some(r#type) => r#type,
+                    None => match found {
+                        types::built_in_types::BuiltInTypes::Null => {
+                            types::built_in_types::BuiltInTypes::Any
+                        }
+                        found => found,
+                    },
+                };
+
+                if self.at_module_scope() {
+                    let name = self.qualified(name);
+                    self.declare_visibility(&name, *public);
+                }
+
+                self.declare_variable(
+                    name,
+                    r#type,
+                    matches!(kind, types::parse_nodes::LetDeclarationKind::Mutable),
+                    value.is_some(),
+                );
+            }
+            types::parse_nodes::Statement::ConstantDeclaration {
+                start,
+                public,
+                name,
+                r#type,
+                value,
+            } => {
+                self.position = start.to_owned();
+
+                // A struct type is checked against the full name of its struct
+                let r#type = match r#type {
+                    Some(r#type) => self.declared_type(
+                        r#type,
+                        format!("constant \"{}\"", name.as_deref().unwrap_or_default()).as_str(),
+                    ),
+                    None => types::built_in_types::BuiltInTypes::Any,
+                };
+
+                let value = match self.evaluate(value).and_then(|value| {
+                    let found = operations::type_name(&value);
+
+                    operations::convert(value, &r#type).ok_or(format!(
+                        "the value is {} but the constant is {}",
+                        found, r#type
+                    ))
+                }) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        self.error(
+                            format!("invalid constant, {}.", message).as_str(),
+                            handlers::error_handler::ErrorCode::E024,
+                        );
+                        return;
+                    }
+                };
+
+                let name = name.as_deref().unwrap_or_default();
+
+                // Constants of the module scope are named with the namespace
+                let name = match self.at_module_scope() {
+                    true => {
+                        let name = self.qualified(name);
+                        self.declare_visibility(&name, *public);
+                        name
+                    }
+                    false => name.to_string(),
+                };
+
+                if self.constants[self.scope_constants..]
+                    .iter()
+                    .any(|(constant, _)| *constant == name)
+                {
+                    self.error(
+                        format!("constant \"{}\" is already declared in this scope.", name)
+                            .as_str(),
+                        handlers::error_handler::ErrorCode::E031,
+                    );
+                }
+
+                self.constants.push((name, value));
+            }
+            types::parse_nodes::Statement::VariableAlteration {
+                start,
+                name,
+                operator,
+                value,
+            } => {
+                self.position = start.to_owned();
+
+                let initializes = operator.eq(&types::tokens::TokenType::OpAssign);
+                let variable = match self.lookup_assigned(name, initializes) {
+                    Some(variable) => variable,
+                    None => return,
+                };
+
+                let operation = match self.assignment_operation(operator) {
+                    Some(operation) => operation,
+                    None => return,
+                };
+
+                let found = match operation {
+                    Some(operation) => {
+                        code.push(bytecode::ByteNode::Pull((
+                            variable.scope.to_owned(),
+                            variable.index,
+                        )));
+                        let found = self.compile_value(value, code);
+                        code.push(operation);
+
+                        match self.operation_type(operator, &variable.r#type, &found) {
+                            Some(found) => found,
+                            None => return,
+                        }
+                    }
+                    None => self.compile_value(value, code),
+                };
+
+                if !self.coerce(&variable.r#type, &found, code, || {
+                    format!(
+                        "\"{}\" is {} but the value is {}.",
+                        name, variable.r#type, found
+                    )
+                }) {
+                    return;
+                }
+
+                self.initialize(&variable);
+                code.push(bytecode::ByteNode::Store((variable.scope, variable.index)));
+            }
+            types::parse_nodes::Statement::FieldAlteration {
+                start,
+                name,
+                fields,
+                operator,
+                value,
+            } => {
+                self.position = start.to_owned();
+
+                let variable = match self.lookup_assigned(name, false) {
+                    Some(variable) => variable,
+                    None => return,
+                };
+
+                // The type of each struct in the path, the last one is the type of the altered field
+                let mut r#type = variable.r#type.to_owned();
+
+                for field in fields {
+                    r#type = match self.field_type(&r#type, field) {
+                        Some(r#type) => r#type,
+                        None => return,
+                    };
+                }
+
+                let operation = match self.assignment_operation(operator) {
+                    Some(operation) => operation,
+                    None => return,
+                };
+
+                // Push the structs that hold the altered field -> name, name.a for name.a.b
+                let pull = |depth: usize, code: &mut Vec<bytecode::ByteNode>| {
+                    code.push(bytecode::ByteNode::Pull((
+                        variable.scope.to_owned(),
+                        variable.index,
+                    )));
+
+                    for field in &fields[..depth] {
+                        code.push(bytecode::ByteNode::GetField(field.to_owned()));
+                    }
+                };
+
+                for depth in 0..fields.len() {
+                    pull(depth, code);
+                }
+
+                let found = match operation {
+                    Some(operation) => {
+                        pull(fields.len(), code);
+                        let found = self.compile_value(value, code);
+                        code.push(operation);
+
+                        match self.operation_type(operator, &r#type, &found) {
+                            Some(found) => found,
+                            None => return,
+                        }
+                    }
+                    None => self.compile_value(value, code),
+                };
+
+                // The field converts the value when it runs
+                if !r#type.accepts(&found) {
+                    self.error(
+                        format!(
+                            "field \"{}\" is {} but the value is {}.",
+                            fields.join("."),
+                            r#type,
+                            found
+                        )
+                        .as_str(),
+                        handlers::error_handler::ErrorCode::E029,
+                    );
+                    return;
+                }
+
+                // Each struct takes the altered one as its field, from the innermost to the variable
+                for field in fields.iter().rev() {
+                    code.push(bytecode::ByteNode::SetField(field.to_owned()));
+                }
+
+                code.push(bytecode::ByteNode::Store((variable.scope, variable.index)));
+            }
+            types::parse_nodes::Statement::If {
+                start,
+                condition,
+                body,
+                alternate,
+            }
+            | types::parse_nodes::Statement::ElseIf {
+                start,
+                condition,
+                body,
+                alternate,
+            } => {
+                self.position = start.to_owned();
+                self.compile_value(condition, code);
+
+                // Skip the body when the condition is false <- the target is known after the body
+                let skip_body = code.len();
+                code.push(bytecode::ByteNode::JumpIfFalse(0));
+
+                // Each path starts with the initializations before the statement
+                let before = self.initializations();
+                self.diverges = false;
+
+                if let Some(body) = body {
+                    self.compile_block(body, code);
+                }
+
+                let body = (self.initializations(), self.diverges);
+                self.set_initializations(&before);
+                self.diverges = false;
+
+                match alternate {
+                    Some(alternate) => {
+                        // The end of the body skips the alternate chain
+                        let skip_alternate = code.len();
+                        code.push(bytecode::ByteNode::Jump(0));
+                        code[skip_body] = bytecode::ByteNode::JumpIfFalse(code.len());
+
+                        for statement in alternate.iter() {
+                            self.compile_statement(statement, code);
+                        }
+
+                        code[skip_alternate] = bytecode::ByteNode::Jump(code.len());
+                    }
+                    None => code[skip_body] = bytecode::ByteNode::JumpIfFalse(code.len()),
+                }
+
+                let diverges = body.1 && self.diverges;
+                let alternate = (self.initializations(), self.diverges);
+
+                self.join_paths(body, alternate);
+                self.diverges = diverges;
+            }
+            types::parse_nodes::Statement::Else { start, body } => {
+                self.position = start.to_owned();
+
+                if let Some(body) = body {
+                    self.compile_block(body, code);
+                }
+            }
+            types::parse_nodes::Statement::While {
+                start,
+                condition,
+                body,
+            } => {
+                self.position = start.to_owned();
+
+                // The body can run no time at all
+                let before = self.initializations();
+
+                // A condition that is always true only ends with a break
+                let infinite = self
+                    .evaluate(condition)
+                    .is_ok_and(|value| operations::is_truthy(&value));
+
+                // The condition is checked before every iteration
+                let loop_start = code.len();
+                self.compile_value(condition, code);
+
+                let exit = code.len();
+                code.push(bytecode::ByteNode::JumpIfFalse(0));
+
+                let context = self.compile_loop_body(body.as_deref().map(Vec::as_slice), code);
+                code.push(bytecode::ByteNode::Jump(loop_start));
+
+                code[exit] = bytecode::ByteNode::JumpIfFalse(code.len());
+
+                for jump in context.continues {
+                    code[jump] = bytecode::ByteNode::Jump(loop_start);
+                }
+
+                for jump in context.breaks {
+                    code[jump] = bytecode::ByteNode::Jump(code.len());
+                }
+
+                // The end of the body and the continues start the next iteration
+                let mut repeats = context.repeats;
+
+                if !self.diverges {
+                    repeats.push(self.initializations());
+                }
+
+                self.position = start.to_owned();
+                self.check_repeats(&before, &repeats);
+
+                // The condition ends the loop before any iteration, and the breaks in one
+                let mut exits = context.exits;
+
+                if !infinite {
+                    exits.extend(Compiler::join_states(
+                        std::iter::once(before.to_owned()).chain(repeats),
+                    ));
+                }
+
+                self.leave_loop(&before, exits);
+            }
+            types::parse_nodes::Statement::For {
+                start,
+                variable,
+                condition,
+                variable_update,
+                body,
+                alternate,
+            } => {
+                self.position = start.to_owned();
+
+                // The loop variable lives in a block around the loop
+                self.blocks().push(vec![]);
+
+                if let Some(variable) = variable {
+                    self.compile_statement(variable, code);
+                }
+
+                // The body can run no time at all, and a break skips the alternate
+                let before = self.initializations();
+
+                // Without a condition, or with one that is always true, it only ends with a break
+                let infinite = condition.as_ref().is_none_or(|condition| {
+                    self.evaluate(condition)
+                        .is_ok_and(|value| operations::is_truthy(&value))
+                });
+
+                // The condition is checked before every iteration
+                let loop_start = code.len();
+                let exit = match condition {
+                    Some(condition) => {
+                        self.compile_value(condition, code);
+                        code.push(bytecode::ByteNode::JumpIfFalse(0));
+                        Some(code.len() - 1)
+                    }
+                    None => None,
+                };
+
+                let context = self.compile_loop_body(body.as_deref().map(Vec::as_slice), code);
+
+                // A continue goes to the variable update
+                for jump in context.continues {
+                    code[jump] = bytecode::ByteNode::Jump(code.len());
+                }
+
+                // The update runs after the end of the body and after the continues
+                let mut update = context.repeats;
+
+                if !self.diverges {
+                    update.push(self.initializations());
+                }
+
+                match Compiler::join_states(update) {
+                    Some(update) => {
+                        self.set_initializations(&update);
+                        self.diverges = false;
+                    }
+                    None => self.diverges = true,
+                }
+
+                // The update runs in every iteration, like the body
+                if let Some(variable_update) = variable_update {
+                    let depth = self.live_variables();
+
+                    self.loops.push(LoopContext::new(depth));
+                    self.compile_statement(variable_update, code);
+                    self.loops.pop();
+                }
+
+                let repeats: Vec<Vec<Initialization>> = match self.diverges {
+                    true => vec![],
+                    false => vec![self.initializations()],
+                };
+
+                self.position = start.to_owned();
+                self.check_repeats(&before, &repeats);
+
+                code.push(bytecode::ByteNode::Jump(loop_start));
+
+                // The alternate only runs when the condition ends the loop, a break skips it
+                if let Some(exit) = exit {
+                    code[exit] = bytecode::ByteNode::JumpIfFalse(code.len());
+                }
+
+                let mut exits = context.exits;
+                let ended = match infinite {
+                    true => None,
+                    false => {
+                        Compiler::join_states(std::iter::once(before.to_owned()).chain(repeats))
+                    }
+                };
+
+                match &ended {
+                    Some(ended) => {
+                        self.set_initializations(ended);
+                        self.diverges = false;
+                    }
+                    None => self.diverges = true,
+                }
+
+                if let Some(alternate) = alternate {
+                    self.compile_block(alternate, code);
+                }
+
+                if !self.diverges {
+                    exits.push(self.initializations());
+                }
+
+                for jump in context.breaks {
+                    code[jump] = bytecode::ByteNode::Jump(code.len());
+                }
+
+                self.leave_loop(&before, exits);
+
+                if let Some(block) = self.blocks().pop() {
+                    for variable in block.iter().rev() {
+                        code.push(bytecode::ByteNode::Pop(variable.index));
+                    }
+                }
+            }
+            types::parse_nodes::Statement::Break { start }
+            | types::parse_nodes::Statement::Continue { start } => {
+                self.position = start.to_owned();
+
+                // The loop continues with the initializations of the reachable exits
+                let initializations = match self.diverges {
+                    true => None,
+                    false => Some(self.initializations()),
+                };
+
+                match self.compile_loop_exit(code) {
+                    Some(jump) => match (statement, self.loops.last_mut()) {
+                        (types::parse_nodes::Statement::Break { .. }, Some(context)) => {
+                            context.breaks.push(jump);
+                            context.exits.extend(initializations);
+                        }
+                        (_, Some(context)) => {
+                            context.continues.push(jump);
+                            context.repeats.extend(initializations);
+                        }
+                        _ => {}
+                    },
+                    None => self.error(
+                        format!(
+                            "\"{}\" can only be used inside a loop.",
+                            statement_name(statement)
+                        )
+                        .as_str(),
+                        handlers::error_handler::ErrorCode::E007,
+                    ),
+                }
+
+                self.diverges = true;
+            }
+            types::parse_nodes::Statement::Return { start, expression } => {
+                self.position = start.to_owned();
+
+                let (function, returns) = match &self.function {
+                    Some(function) => (function.name.to_owned(), function.returns.to_owned()),
+                    None => {
+                        self.error(
+                            "\"return\" can only be used inside a function.",
+                            handlers::error_handler::ErrorCode::E009,
+                        );
+                        return;
+                    }
+                };
+
+                // The variables of the function are dropped with its frame
+                match expression {
+                    Some(expression) => {
+                        let found = self.compile_value(expression, code);
+
+                        // A void function can only return null
+                        match (&returns, &found) {
+                            (
+                                types::built_in_types::BuiltInTypes::Void,
+                                types::built_in_types::BuiltInTypes::Null,
+                            ) => {}
+                            (types::built_in_types::BuiltInTypes::Void, _) => self.error(
+                                format!(
+                                    "\"{}\" returns void but a {} is returned.",
+                                    function, found
+                                )
+                                .as_str(),
+                                handlers::error_handler::ErrorCode::E029,
+                            ),
+                            _ => {
+                                self.coerce(&returns, &found, code, || {
+                                    format!(
+                                        "\"{}\" returns {} but the value is {}.",
+                                        function, returns, found
+                                    )
+                                });
+                            }
+                        }
+                    }
+                    None => {
+                        if !matches!(
+                            returns,
+                            types::built_in_types::BuiltInTypes::Any
+                                | types::built_in_types::BuiltInTypes::Void
+                                | types::built_in_types::BuiltInTypes::Null
+                        ) {
+                            self.error(
+                                format!(
+                                    "\"{}\" returns {} but no value is returned.",
+                                    function, returns
+                                )
+                                .as_str(),
+                                handlers::error_handler::ErrorCode::E029,
+                            );
+                        }
+
+                        code.push(bytecode::ByteNode::Push(bytecode::Value::Null));
+                    }
+                }
+
+                code.push(bytecode::ByteNode::Ret);
+                self.diverges = true;
+            }
+            types::parse_nodes::Statement::Block { start, body } => {
+                self.position = start.to_owned();
+                self.compile_block(body, code);
+            }
+            types::parse_nodes::Statement::Use { start, path } => {
+                self.position = start.to_owned();
+
+                if !self.is_known_path(path) {
+                    self.error(
+                        format!("\"{}\" can't be found.", path).as_str(),
+                        handlers::error_handler::ErrorCode::E020,
+                    );
+                    return;
+                }
+
+                self.check_visibility(path);
+                self.import(path);
+            }
+            types::parse_nodes::Statement::FunctionCall { start, expression } => {
+                self.position = start.to_owned();
+
+                // A void function can be called as a statement
+                self.compile_expression(expression, code);
+
+                // Discard the returned value
+                let index = self.live_variables();
+                code.push(bytecode::ByteNode::Pop(index));
+            }
+            types::parse_nodes::Statement::FuctionDeclaration { start, .. } => {
+                self.position = start.to_owned();
+                self.error(
+                    "functions can only be declared at module level.",
+                    handlers::error_handler::ErrorCode::E003,
+                );
+            }
+            types::parse_nodes::Statement::StructDeclaration { start, .. }
+            | types::parse_nodes::Statement::Impl { start, .. } => {
+                self.position = start.to_owned();
+                self.error(
+                    "structs and their methods can only be declared at module level.",
+                    handlers::error_handler::ErrorCode::E003,
+                );
+            }
+            _ => self.error(
+                format!(
+                    "{} statement is not supported by the code generator.",
+                    statement_name(statement)
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E003,
+            ),
+        }
+    }
+
+    /// Compile a expression whose value is used, a void value is reported and taken as "any"
+    fn compile_value(
+        &mut self,
+        expression: &types::parse_nodes::Expression,
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> types::built_in_types::BuiltInTypes {
+        match self.compile_expression(expression, code) {
+            types::built_in_types::BuiltInTypes::Void => {
+                self.error(
+                    "a void value can't be used.",
+                    handlers::error_handler::ErrorCode::E030,
+                );
+                types::built_in_types::BuiltInTypes::Any
+            }
+            r#type => r#type,
+        }
+    }
+
+    /// Check that a value of the found type can be given where the expected type is
+    /// The value is converted when it runs if the types aren't the same -> int to float, any to int
+    fn coerce<F: FnOnce() -> String>(
+        &mut self,
+        expected: &types::built_in_types::BuiltInTypes,
+        found: &types::built_in_types::BuiltInTypes,
+        code: &mut Vec<bytecode::ByteNode>,
+        message: F,
+    ) -> bool {
+        if !expected.accepts(found) {
+            self.error(message().as_str(), handlers::error_handler::ErrorCode::E029);
+            return false;
+        }
+
+        if expected != found && *expected != types::built_in_types::BuiltInTypes::Any {
+            code.push(bytecode::ByteNode::Convert(expected.to_owned()));
+        }
+
+        true
+    }
+
+    /// The type of a binary operation, operands that the operator can't use are reported
+    fn binary_type(
+        &mut self,
+        operator: &types::tokens::TokenType,
+        left: &types::built_in_types::BuiltInTypes,
+        right: &types::built_in_types::BuiltInTypes,
+    ) -> Option<types::built_in_types::BuiltInTypes> {
+        let r#type = types::built_in_types::BuiltInTypes::binary(operator, left, right);
+
+        if r#type.is_none() {
+            self.error(
+                format!(
+                    "can't apply \"{}\" to {} and {}.",
+                    operator_symbol(operator),
+                    left,
+                    right
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E029,
+            );
+        }
+
+        r#type
+    }
+
+    /// The type of a compound assignment on the current value -> x += 1 <- x + 1
+    fn operation_type(
+        &mut self,
+        operator: &types::tokens::TokenType,
+        current: &types::built_in_types::BuiltInTypes,
+        value: &types::built_in_types::BuiltInTypes,
+    ) -> Option<types::built_in_types::BuiltInTypes> {
+        let operator = match operator {
+            types::tokens::TokenType::OpAssignPlus => types::tokens::TokenType::OpPlus,
+            types::tokens::TokenType::OpAssignMinus => types::tokens::TokenType::OpMinus,
+            types::tokens::TokenType::OpAssignMultiply => types::tokens::TokenType::OpMultiply,
+            types::tokens::TokenType::OpAssignDivision => types::tokens::TokenType::OpDivision,
+            types::tokens::TokenType::OpAssignRest => types::tokens::TokenType::OpRest,
+            operator => operator.to_owned(),
+        };
+
+        self.binary_type(&operator, current, value)
+    }
+
+    /// Check the arguments of a call against the parameter types of the function
+    fn check_arguments(
+        &mut self,
+        function: &Function,
+        arguments: &[types::built_in_types::BuiltInTypes],
+    ) -> bool {
+        let mismatch = function
+            .params
+            .iter()
+            .zip(arguments)
+            .enumerate()
+            .find(|(_, (expected, found))| !expected.accepts(found));
+
+        if let Some((index, (expected, found))) = mismatch {
+            self.error(
+                format!(
+                    "argument {} of \"{}\" is {} but the value is {}.",
+                    index + 1,
+                    function.name,
+                    expected,
+                    found
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E029,
+            );
+        }
+
+        mismatch.is_none()
+    }
+
+    /// Compile a expression and return the type of its value, it's "any" after a error
+    fn compile_expression(
+        &mut self,
+        expression: &types::parse_nodes::Expression,
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> types::built_in_types::BuiltInTypes {
+        match expression {
+            types::parse_nodes::Expression::Literal { r#type, value } => {
+                match compile_literal(r#type, value) {
+                    Some(value) => {
+                        let r#type = operations::type_of(&value);
+                        code.push(bytecode::ByteNode::Push(value));
+                        r#type
+                    }
+                    None => {
+                        self.error(
+                            format!("{} is not a valid literal.", value).as_str(),
+                            handlers::error_handler::ErrorCode::E006,
+                        );
+                        types::built_in_types::BuiltInTypes::Any
+                    }
+                }
+            }
+            types::parse_nodes::Expression::Identifier(name) => {
+                if let Some(variable) = self.lookup_variable(name) {
+                    self.check_visibility(&variable.name);
+                    self.check_initialized(&variable);
+                    code.push(bytecode::ByteNode::Pull((variable.scope, variable.index)));
+                    return variable.r#type;
+                }
+
+                match self.resolve_constant(name) {
+                    Some((name, value)) => {
+                        self.check_visibility(&name);
+
+                        let r#type = operations::type_of(&value);
+                        code.push(bytecode::ByteNode::Push(value));
+                        r#type
+                    }
+                    None => {
+                        self.error(
+                            format!("\"{}\" is not defined.", name).as_str(),
+                            handlers::error_handler::ErrorCode::E004,
+                        );
+                        types::built_in_types::BuiltInTypes::Any
+                    }
+                }
+            }
+            types::parse_nodes::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left = self.compile_value(left, code);
+                let right = self.compile_value(right, code);
+
+                match operator {
+                    types::tokens::TokenType::OpPlus => code.push(bytecode::ByteNode::Add),
+                    types::tokens::TokenType::OpMinus => code.push(bytecode::ByteNode::Sub),
+                    types::tokens::TokenType::OpMultiply => code.push(bytecode::ByteNode::Mul),
+                    types::tokens::TokenType::OpDivision => code.push(bytecode::ByteNode::Div),
+                    types::tokens::TokenType::OpRest => code.push(bytecode::ByteNode::Mod),
+                    types::tokens::TokenType::OpEquals => code.push(bytecode::ByteNode::Eq),
+                    types::tokens::TokenType::OpNotEquals => code.push(bytecode::ByteNode::Ne),
+                    types::tokens::TokenType::OpSmallerThan => code.push(bytecode::ByteNode::Lt),
+                    types::tokens::TokenType::OpGreaterThan => code.push(bytecode::ByteNode::Gt),
+                    types::tokens::TokenType::OpSmallerOrEqualsThan => {
+                        code.push(bytecode::ByteNode::Le)
+                    }
+                    types::tokens::TokenType::OpGreaterOrEqualsThan => {
+                        code.push(bytecode::ByteNode::Ge)
+                    }
+                    _ => {
+                        self.error(
+                            format!(
+                                "{:?} operator is not supported by the code generator.",
+                                operator
+                            )
+                            .as_str(),
+                            handlers::error_handler::ErrorCode::E003,
+                        );
+                        return types::built_in_types::BuiltInTypes::Any;
+                    }
+                }
+
+                self.binary_type(operator, &left, &right)
+                    .unwrap_or(types::built_in_types::BuiltInTypes::Any)
+            }
+            types::parse_nodes::Expression::Logical {
+                operator,
+                left,
+                right,
+            } => {
+                // The right operand is only evaluated when the left one doesn't decide the result
+                // and -> a false left is false, or -> a true left is true
+                self.compile_value(left, code);
+
+                let skip_left = code.len();
+                code.push(bytecode::ByteNode::JumpIfFalse(0));
+
+                let left_is_true = match operator {
+                    types::tokens::TokenType::OpAnd => None,
+                    _ => {
+                        code.push(bytecode::ByteNode::Jump(0));
+                        code[skip_left] = bytecode::ByteNode::JumpIfFalse(code.len());
+                        Some(code.len() - 1)
+                    }
+                };
+
+                self.compile_value(right, code);
+
+                let right_is_false = code.len();
+                code.push(bytecode::ByteNode::JumpIfFalse(0));
+
+                if let Some(jump) = left_is_true {
+                    code[jump] = bytecode::ByteNode::Jump(code.len());
+                }
+
+                code.push(bytecode::ByteNode::Push(bytecode::Value::Bool(true)));
+                let skip_false = code.len();
+                code.push(bytecode::ByteNode::Jump(0));
+
+                let is_false = code.len();
+                code.push(bytecode::ByteNode::Push(bytecode::Value::Bool(false)));
+
+                code[skip_false] = bytecode::ByteNode::Jump(code.len());
+                code[right_is_false] = bytecode::ByteNode::JumpIfFalse(is_false);
+
+                if left_is_true.is_none() {
+                    code[skip_left] = bytecode::ByteNode::JumpIfFalse(is_false);
+                }
+
+                types::built_in_types::BuiltInTypes::Bool
+            }
+            types::parse_nodes::Expression::Unary { operator, operand } => {
+                let operand = self.compile_value(operand, code);
+
+                match operator {
+                    types::tokens::TokenType::OpNot => code.push(bytecode::ByteNode::Not),
+                    _ => code.push(bytecode::ByteNode::Neg),
+                }
+
+                match types::built_in_types::BuiltInTypes::unary(operator, &operand) {
+                    Some(r#type) => r#type,
+                    None => {
+                        self.error(
+                            format!(
+                                "can't apply \"{}\" to {}.",
+                                operator_symbol(operator),
+                                operand
+                            )
+                            .as_str(),
+                            handlers::error_handler::ErrorCode::E029,
+                        );
+                        types::built_in_types::BuiltInTypes::Any
+                    }
+                }
+            }
+            types::parse_nodes::Expression::Call { name, arguments } => {
+                let (id, function, native) = match self.lookup_callable(name) {
+                    Some(callable) => callable,
+                    None => {
+                        self.error(
+                            format!("function \"{}\" is not defined.", name).as_str(),
+                            handlers::error_handler::ErrorCode::E005,
+                        );
+                        return types::built_in_types::BuiltInTypes::Any;
+                    }
+                };
+
+                if !native {
+                    self.check_visibility(&function.name);
+                }
+
+                let arguments = arguments.as_deref().map(Vec::as_slice).unwrap_or(&[]);
+
+                if arguments.len() != function.params.len() {
+                    self.error(
+                        format!(
+                            "function \"{}\" takes {} arguments but {} were given.",
+                            name,
+                            function.params.len(),
+                            arguments.len()
+                        )
+                        .as_str(),
+                        handlers::error_handler::ErrorCode::E008,
+                    );
+                    return types::built_in_types::BuiltInTypes::Any;
+                }
+
+                // The arguments are pushed in order, the first one is the deepest
+                let found: Vec<types::built_in_types::BuiltInTypes> = arguments
+                    .iter()
+                    .map(|argument| self.compile_value(argument, code))
+                    .collect();
+
+                if !self.check_arguments(&function, &found) {
+                    return types::built_in_types::BuiltInTypes::Any;
+                }
+
+                if !native {
+                    self.record_call();
+                }
+
+                // The called function converts the arguments to its parameter types
+                code.push(match native {
+                    true => bytecode::ByteNode::CallNative((id, arguments.len())),
+                    false => bytecode::ByteNode::Call((id, arguments.len())),
+                });
+
+                function.returns
+            }
+            types::parse_nodes::Expression::ArrayLiteral { elements } => {
+                let elements = elements.as_deref().map(Vec::as_slice).unwrap_or(&[]);
+
+                for element in elements {
+                    self.compile_value(element, code);
+                }
+
+                code.push(bytecode::ByteNode::MakeArray(elements.len()));
+
+                // The elements of a array can be of any type
+                types::built_in_types::BuiltInTypes::Any
+            }
+            types::parse_nodes::Expression::ArrayAcess(acess) => self.compile_acess(acess, code),
+            types::parse_nodes::Expression::MethodCall {
+                object,
+                method,
+                arguments,
+            } => {
+                let arguments = arguments.as_deref().map(Vec::as_slice).unwrap_or(&[]);
+
+                // The value is the first argument, its "self"
+                let object = self.compile_value(object, code);
+
+                match object {
+                    types::built_in_types::BuiltInTypes::Struct(name) => {
+                        self.compile_method(&name, method, arguments, code)
+                    }
+                    types::built_in_types::BuiltInTypes::Any => {
+                        self.compile_dynamic_method(method, arguments, code)
+                    }
+                    object => {
+                        self.error(
+                            format!("{} has no methods.", object).as_str(),
+                            handlers::error_handler::ErrorCode::E029,
+                        );
+                        types::built_in_types::BuiltInTypes::Any
+                    }
+                }
+            }
+            types::parse_nodes::Expression::StructLiteral { name, fields } => {
+                let declaration = match self.struct_literal(name, fields) {
+                    Ok(declaration) => declaration,
+                    Err((message, code)) => {
+                        self.error(message.as_str(), code);
+                        return types::built_in_types::BuiltInTypes::Any;
+                    }
+                };
+
+                self.check_visibility(&self.structs[declaration].name.to_owned());
+
+                // The values are pushed in the declaration order of the fields
+                let declared = self.structs[declaration].fields.to_owned();
+
+                for (field, r#type) in declared {
+                    let value = match fields.iter().find(|(name, _)| *name == field) {
+                        Some((_, value)) => value,
+                        None => continue,
+                    };
+
+                    // The struct converts the value to the field type when it runs
+                    let found = self.compile_value(value, code);
+
+                    if !r#type.accepts(&found) {
+                        self.error(
+                            format!(
+                                "field \"{}\" of \"{}\" is {} but the value is {}.",
+                                field, name, r#type, found
+                            )
+                            .as_str(),
+                            handlers::error_handler::ErrorCode::E027,
+                        );
+                        return types::built_in_types::BuiltInTypes::Any;
+                    }
+                }
+
+                code.push(bytecode::ByteNode::MakeStruct(declaration));
+
+                types::built_in_types::BuiltInTypes::Struct(
+                    self.structs[declaration].name.to_owned(),
+                )
+            }
+            types::parse_nodes::Expression::FieldAcess { object, field } => {
+                let object = self.compile_value(object, code);
+
+                match self.field_type(&object, field) {
+                    Some(r#type) => {
+                        code.push(bytecode::ByteNode::GetField(field.to_owned()));
+                        r#type
+                    }
+                    None => types::built_in_types::BuiltInTypes::Any,
+                }
+            }
+        }
+    }
+
+    /// Compile the call of a method on a value of a known struct, it's a call to struct::method
+    /// The value is already pushed, it's the first argument
+    fn compile_method(
+        &mut self,
+        owner: &str,
+        method: &str,
+        arguments: &[types::parse_nodes::Expression],
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> types::built_in_types::BuiltInTypes {
+        let name = format!("{}::{}", owner, method);
+
+        let (id, function) = match self.lookup_function(&name) {
+            Some(id) if self.methods.iter().any(|method| method.name == name) => {
+                (id, self.functions[id].to_owned())
+            }
+            _ => {
+                self.error(
+                    format!("struct \"{}\" has no method \"{}\".", owner, method).as_str(),
+                    handlers::error_handler::ErrorCode::E028,
+                );
+                return types::built_in_types::BuiltInTypes::Any;
+            }
+        };
+
+        self.check_visibility(&name);
+
+        if function.params.len() != arguments.len() + 1 {
+            self.error(
+                format!(
+                    "method \"{}\" takes {} arguments but {} were given.",
+                    method,
+                    function.params.len() - 1,
+                    arguments.len()
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E008,
+            );
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        let mut found = vec![types::built_in_types::BuiltInTypes::Struct(
+            owner.to_string(),
+        )];
+
+        for argument in arguments {
+            found.push(self.compile_value(argument, code));
+        }
+
+        if !self.check_arguments(&function, &found) {
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        self.record_call();
+        code.push(bytecode::ByteNode::Call((id, found.len())));
+
+        function.returns
+    }
+
+    /// Compile the call of a method on a value of type "any", its struct is only known when it runs
+    /// The value is already pushed, it's the first argument
+    fn compile_dynamic_method(
+        &mut self,
+        method: &str,
+        arguments: &[types::parse_nodes::Expression],
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> types::built_in_types::BuiltInTypes {
+        // Any method with the name can be called
+        let methods: Vec<Function> = self
+            .methods
+            .iter()
+            .filter(|function| function.name.rsplit("::").next() == Some(method))
+            .cloned()
+            .collect();
+
+        let callable: Vec<&Function> = methods
+            .iter()
+            .filter(|function| function.params.len() == arguments.len() + 1)
+            .collect();
+
+        if methods.is_empty() {
+            self.error(
+                format!("no struct has a method \"{}\".", method).as_str(),
+                handlers::error_handler::ErrorCode::E028,
+            );
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        if callable.is_empty() {
+            self.error(
+                format!(
+                    "method \"{}\" takes {} arguments but {} were given.",
+                    method,
+                    methods[0].params.len() - 1,
+                    arguments.len()
+                )
+                .as_str(),
+                handlers::error_handler::ErrorCode::E008,
+            );
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        if callable
+            .iter()
+            .all(|function| self.is_private(&function.name))
+        {
+            self.check_visibility(&callable[0].name.to_owned());
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        for argument in arguments {
+            self.compile_value(argument, code);
+        }
+
+        self.record_call();
+        code.push(bytecode::ByteNode::CallMethod((
+            method.to_owned(),
+            arguments.len() + 1,
+        )));
+
+        types::built_in_types::BuiltInTypes::Any
+    }
+
+    /// Find the struct of a literal and check that it gives every field once -> struct id
+    fn struct_literal(
+        &self,
+        name: &str,
+        fields: &[(String, types::parse_nodes::Expression)],
+    ) -> Result<usize, (String, handlers::error_handler::ErrorCode)> {
+        let id = self.lookup_struct(name).ok_or((
+            format!("struct \"{}\" is not defined.", name),
+            handlers::error_handler::ErrorCode::E025,
+        ))?;
+        let declaration = &self.structs[id];
+
+        for (index, (field, _)) in fields.iter().enumerate() {
+            if !declaration.fields.iter().any(|(name, _)| name == field) {
+                return Err((
+                    format!("struct \"{}\" has no field \"{}\".", name, field),
+                    handlers::error_handler::ErrorCode::E026,
+                ));
+            }
+
+            if fields[..index].iter().any(|(other, _)| other == field) {
+                return Err((
+                    format!("field \"{}\" is given more than once.", field),
+                    handlers::error_handler::ErrorCode::E027,
+                ));
+            }
+        }
+
+        match declaration
+            .fields
+            .iter()
+            .find(|(field, _)| !fields.iter().any(|(name, _)| name == field))
+        {
+            Some((field, _)) => Err((
+                format!(
+                    "the \"{}\" literal is missing the field \"{}\".",
+                    name, field
+                ),
+                handlers::error_handler::ErrorCode::E027,
+            )),
+            None => Ok(id),
+        }
+    }
+
+    /// Evaluate a constant expression at compile time, only literals, constants and operators can be used
+    fn evaluate(
+        &self,
+        expression: &types::parse_nodes::Expression,
+    ) -> Result<bytecode::Value, String> {
+        match expression {
+            types::parse_nodes::Expression::Literal { r#type, value } => {
+                compile_literal(r#type, value).ok_or(format!("{} is not a valid literal", value))
+            }
+            types::parse_nodes::Expression::Identifier(name) => self
+                .lookup_constant(name)
+                .ok_or(format!("\"{}\" is not a constant", name)),
+            types::parse_nodes::Expression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+
+                let compare = |expected: &[Ordering]| {
+                    operations::compare(&left, &right).map(|ordering| {
+                        bytecode::Value::Bool(
+                            ordering.is_some_and(|ordering| expected.contains(&ordering)),
+                        )
+                    })
+                };
+
+                match operator {
+                    types::tokens::TokenType::OpPlus => {
+                        operations::arithmetic(operations::Arithmetic::Add, &left, &right)
+                    }
+                    types::tokens::TokenType::OpMinus => {
+                        operations::arithmetic(operations::Arithmetic::Sub, &left, &right)
+                    }
+                    types::tokens::TokenType::OpMultiply => {
+                        operations::arithmetic(operations::Arithmetic::Mul, &left, &right)
+                    }
+                    types::tokens::TokenType::OpDivision => {
+                        operations::arithmetic(operations::Arithmetic::Div, &left, &right)
+                    }
+                    types::tokens::TokenType::OpRest => {
+                        operations::arithmetic(operations::Arithmetic::Mod, &left, &right)
+                    }
+                    types::tokens::TokenType::OpEquals => {
+                        Ok(bytecode::Value::Bool(operations::equals(&left, &right)))
+                    }
+                    types::tokens::TokenType::OpNotEquals => {
+                        Ok(bytecode::Value::Bool(!operations::equals(&left, &right)))
+                    }
+                    types::tokens::TokenType::OpSmallerThan => compare(&[Ordering::Less]),
+                    types::tokens::TokenType::OpGreaterThan => compare(&[Ordering::Greater]),
+                    types::tokens::TokenType::OpSmallerOrEqualsThan => {
+                        compare(&[Ordering::Less, Ordering::Equal])
+                    }
+                    types::tokens::TokenType::OpGreaterOrEqualsThan => {
+                        compare(&[Ordering::Greater, Ordering::Equal])
+                    }
+                    _ => return Err(format!("{:?} operator can't be evaluated", operator)),
+                }
+                .map_err(|kind| kind.to_string().trim_end_matches('.').to_string())
+            }
+            types::parse_nodes::Expression::Logical {
+                operator,
+                left,
+                right,
+            } => {
+                let left = operations::is_truthy(&self.evaluate(left)?);
+
+                // The right operand isn't evaluated when the left one decides the result
+                match (operator, left) {
+                    (types::tokens::TokenType::OpAnd, false) => Ok(bytecode::Value::Bool(false)),
+                    (types::tokens::TokenType::OpOr, true) => Ok(bytecode::Value::Bool(true)),
+                    _ => Ok(bytecode::Value::Bool(operations::is_truthy(
+                        &self.evaluate(right)?,
+                    ))),
+                }
+            }
+            types::parse_nodes::Expression::Unary { operator, operand } => {
+                let operand = self.evaluate(operand)?;
+
+                match operator {
+                    types::tokens::TokenType::OpNot => {
+                        Ok(bytecode::Value::Bool(!operations::is_truthy(&operand)))
+                    }
+                    _ => operations::negate(&operand)
+                        .map_err(|kind| kind.to_string().trim_end_matches('.').to_string()),
+                }
+            }
+            types::parse_nodes::Expression::ArrayLiteral { elements } => elements
+                .as_deref()
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
+                .iter()
+                .map(|element| self.evaluate(element))
+                .collect::<Result<Vec<bytecode::Value>, String>>()
+                .map(bytecode::Value::Array),
+            types::parse_nodes::Expression::ArrayAcess(_) => {
+                Err("array acess can't be evaluated at compile time".to_string())
+            }
+            types::parse_nodes::Expression::Call { name, .. } => Err(format!(
+                "the call to \"{}\" can't be evaluated at compile time",
+                name
+            )),
+            types::parse_nodes::Expression::MethodCall { method, .. } => Err(format!(
+                "the call to the method \"{}\" can't be evaluated at compile time",
+                method
+            )),
+            types::parse_nodes::Expression::StructLiteral { name, fields } => {
+                let id = self
+                    .struct_literal(name, fields)
+                    .map_err(|(message, _)| message.trim_end_matches('.').to_string())?;
+                let declaration = &self.structs[id];
+                let mut values: Vec<(String, bytecode::Value)> = vec![];
+
+                for (field, r#type) in declaration.fields.iter() {
+                    let value = match fields.iter().find(|(name, _)| name == field) {
+                        Some((_, value)) => self.evaluate(value)?,
+                        None => continue,
+                    };
+                    let found = operations::type_name(&value);
+
+                    values.push((
+                        field.to_owned(),
+                        operations::convert(value, r#type).ok_or(format!(
+                            "field \"{}\" of \"{}\" is {} but the value is {}",
+                            field, name, r#type, found
+                        ))?,
+                    ));
+                }
+
+                Ok(bytecode::Value::Struct {
+                    name: declaration.name.to_owned(),
+                    fields: values,
+                })
+            }
+            types::parse_nodes::Expression::FieldAcess { object, field } => {
+                operations::field(&self.evaluate(object)?, field)
+                    .map_err(|kind| kind.to_string().trim_end_matches('.').to_string())
+            }
+        }
+    }
+
+    /// Compile a array acess, the inner acess is the one closer to the array -> name[0][1]
+    /// A str gives its chars, the elements of a array can be of any type
+    fn compile_acess(
+        &mut self,
+        acess: &types::parse_nodes::ArrayAcess,
+        code: &mut Vec<bytecode::ByteNode>,
+    ) -> types::built_in_types::BuiltInTypes {
+        let (value, index) = match acess {
+            types::parse_nodes::ArrayAcess::Acess { name, index } => (
+                self.compile_value(
+                    &types::parse_nodes::Expression::Identifier(name.to_owned()),
+                    code,
+                ),
+                self.compile_value(index, code),
+            ),
+            types::parse_nodes::ArrayAcess::NestedAcess { acess, index } => (
+                self.compile_acess(acess, code),
+                self.compile_value(index, code),
+            ),
+        };
+
+        code.push(bytecode::ByteNode::Index);
+
+        if !types::built_in_types::BuiltInTypes::Int.accepts(&index) {
+            self.error(
+                format!("a index must be a int but it's {}.", index).as_str(),
+                handlers::error_handler::ErrorCode::E029,
+            );
+            return types::built_in_types::BuiltInTypes::Any;
+        }
+
+        match value {
+            types::built_in_types::BuiltInTypes::Str => types::built_in_types::BuiltInTypes::Char,
+            types::built_in_types::BuiltInTypes::Any => types::built_in_types::BuiltInTypes::Any,
+            value => {
+                self.error(
+                    format!("{} can't be indexed.", value).as_str(),
+                    handlers::error_handler::ErrorCode::E029,
+                );
+                types::built_in_types::BuiltInTypes::Any
+            }
+        }
+    }
+}
+
+/// Convert a literal token into a runtime value
+fn compile_literal(r#type: &types::tokens::TokenType, value: &str) -> Option<bytecode::Value> {
+    match r#type {
+        types::tokens::TokenType::Number => value.parse::<i32>().ok().map(bytecode::Value::Int),
+        types::tokens::TokenType::DecimalNumber => {
+            value.parse::<f32>().ok().map(bytecode::Value::Float)
+        }
+        types::tokens::TokenType::True => Some(bytecode::Value::Bool(true)),
+        types::tokens::TokenType::False => Some(bytecode::Value::Bool(false)),
+        types::tokens::TokenType::TypeNull => Some(bytecode::Value::Null),
+        types::tokens::TokenType::StringLiteral => {
+            Some(bytecode::Value::String(unescape(unquote(value)?)?))
+        }
+        types::tokens::TokenType::CharLiteral => {
+            let mut chars = unescape(unquote(value)?)?.chars().collect::<Vec<char>>();
+
+            match chars.len() {
+                1 => chars.pop().map(bytecode::Value::Char),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Remove the quotes around a string or char literal
+fn unquote(value: &str) -> Option<&str> {
+    if value.len() < 2 {
+        return None;
+    }
+
+    value.get(1..value.len() - 1)
+}
+
+/// Replace the escape sequences of a literal by the actual characters
+fn unescape(value: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            'b' => result.push('\u{8}'),
+            'f' => result.push('\u{c}'),
+            '0' => result.push('\0'),
+            '"' => result.push('"'),
+            '\'' => result.push('\''),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+/// The symbol of a operator, used in error messages
+fn operator_symbol(operator: &types::tokens::TokenType) -> &'static str {
+    match operator {
+        types::tokens::TokenType::OpPlus => "+",
+        types::tokens::TokenType::OpMinus => "-",
+        types::tokens::TokenType::OpMultiply => "*",
+        types::tokens::TokenType::OpDivision => "/",
+        types::tokens::TokenType::OpRest => "%",
+        types::tokens::TokenType::OpNot => "not",
+        types::tokens::TokenType::OpAnd => "and",
+        types::tokens::TokenType::OpOr => "or",
+        types::tokens::TokenType::OpSmallerThan => "<",
+        types::tokens::TokenType::OpGreaterThan => ">",
+        types::tokens::TokenType::OpSmallerOrEqualsThan => "<=",
+        types::tokens::TokenType::OpGreaterOrEqualsThan => ">=",
+        types::tokens::TokenType::OpEquals => "==",
+        types::tokens::TokenType::OpNotEquals => "!=",
+        _ => "?",
+    }
+}
+
+/// A readable name of a statement kind, used in error messages
+fn statement_name(statement: &types::parse_nodes::Statement) -> &'static str {
+    match statement {
+        types::parse_nodes::Statement::Module { .. } => "module",
+        types::parse_nodes::Statement::Use { .. } => "use",
+        types::parse_nodes::Statement::Namespace { .. } => "namespace",
+        types::parse_nodes::Statement::LetDeclaration { .. } => "let",
+        types::parse_nodes::Statement::ConstantDeclaration { .. } => "const",
+        types::parse_nodes::Statement::StructDeclaration { .. } => "struct",
+        types::parse_nodes::Statement::Impl { .. } => "impl",
+        types::parse_nodes::Statement::FuctionDeclaration { .. } => "function",
+        types::parse_nodes::Statement::If { .. } => "if",
+        types::parse_nodes::Statement::ElseIf { .. } => "elif",
+        types::parse_nodes::Statement::Else { .. } => "else",
+        types::parse_nodes::Statement::While { .. } => "while",
+        types::parse_nodes::Statement::For { .. } => "for",
+        types::parse_nodes::Statement::Break { .. } => "break",
+        types::parse_nodes::Statement::Continue { .. } => "continue",
+        types::parse_nodes::Statement::Return { .. } => "return",
+        types::parse_nodes::Statement::VariableAlteration { .. } => "assignment",
+        types::parse_nodes::Statement::FieldAlteration { .. } => "field assignment",
+        types::parse_nodes::Statement::FunctionCall { .. } => "function call",
+        types::parse_nodes::Statement::Block { .. } => "block",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::{lexer, parser};
+
+    /// Compile a source and return the instructions of its global code
+    fn global_code(source: &str) -> Vec<String> {
+        let mut lexer = lexer::Lexer::new();
+        lexer.lex(source);
+
+        let mut parser = parser::Parser::new(lexer.tokens);
+        parser.parse_tokens();
+
+        let mut compiler = Compiler::new();
+        let bytecode = compiler.compile(&parser.ast);
+        assert!(compiler.errors.is_empty(), "{:?}", compiler.errors);
+
+        match bytecode.code.into_iter().next() {
+            Some(bytecode::Scope::Global(code)) => {
+                code.iter().map(|node| format!("{:?}", node)).collect()
+            }
+            scope => panic!("expected the global code, got {:?}", scope),
+        }
+    }
+
+    #[test]
+    fn and_jumps_over_the_right_operand_when_the_left_is_false() {
+        let code = global_code("let a = false; let b = true; let c = a and b;");
+
+        assert_eq!(
+            code[2..],
+            [
+                "Pull((Global, 0))",
+                // A false left goes straight to the false result
+                "JumpIfFalse(8)",
+                "Pull((Global, 1))",
+                "JumpIfFalse(8)",
+                "Push(Bool(true))",
+                "Jump(9)",
+                "Push(Bool(false))",
+            ]
+        );
+    }
+
+    #[test]
+    fn or_jumps_over_the_right_operand_when_the_left_is_true() {
+        let code = global_code("let a = true; let b = false; let c = a or b;");
+
+        assert_eq!(
+            code[2..],
+            [
+                "Pull((Global, 0))",
+                "JumpIfFalse(5)",
+                // A true left goes straight to the true result
+                "Jump(7)",
+                "Pull((Global, 1))",
+                "JumpIfFalse(9)",
+                "Push(Bool(true))",
+                "Jump(10)",
+                "Push(Bool(false))",
+            ]
+        );
+    }
+}
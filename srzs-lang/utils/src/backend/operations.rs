@@ -0,0 +1,299 @@
+use crate::backend::bytecode;
+use crate::handlers::error_handler::RuntimeErrorKind;
+use crate::types::built_in_types::BuiltInTypes;
+use std::cmp::Ordering;
+
+/// Name of the value type, used in error messages <- a struct is named by its declaration
+pub fn type_name(value: &bytecode::Value) -> String {
+    match value {
+        bytecode::Value::Null => "null".to_string(),
+        bytecode::Value::Bool(_) => "bool".to_string(),
+        bytecode::Value::Char(_) => "char".to_string(),
+        bytecode::Value::Int(_) => "int".to_string(),
+        bytecode::Value::Float(_) => "float".to_string(),
+        bytecode::Value::String(_) => "str".to_string(),
+        bytecode::Value::Array(_) => "array".to_string(),
+        bytecode::Value::Struct { name, .. } => name.to_owned(),
+    }
+}
+
+/// Type of a value known at compile time <- the elements of a array can be of any type
+pub fn type_of(value: &bytecode::Value) -> BuiltInTypes {
+    match value {
+        bytecode::Value::Null => BuiltInTypes::Null,
+        bytecode::Value::Bool(_) => BuiltInTypes::Bool,
+        bytecode::Value::Char(_) => BuiltInTypes::Char,
+        bytecode::Value::Int(_) => BuiltInTypes::Int,
+        bytecode::Value::Float(_) => BuiltInTypes::Float,
+        bytecode::Value::String(_) => BuiltInTypes::Str,
+        bytecode::Value::Array(_) => BuiltInTypes::Any,
+        bytecode::Value::Struct { name, .. } => BuiltInTypes::Struct(name.to_owned()),
+    }
+}
+
+/// Convert a value to a declared type, a int is promoted when a float is expected
+pub fn convert(value: bytecode::Value, r#type: &BuiltInTypes) -> Option<bytecode::Value> {
+    match (r#type, value) {
+        (BuiltInTypes::Any, value) => Some(value),
+        (BuiltInTypes::Null | BuiltInTypes::Void, bytecode::Value::Null) => {
+            Some(bytecode::Value::Null)
+        }
+        (BuiltInTypes::Bool, value @ bytecode::Value::Bool(_))
+        | (BuiltInTypes::Char, value @ bytecode::Value::Char(_))
+        | (BuiltInTypes::Int, value @ bytecode::Value::Int(_))
+        | (BuiltInTypes::Float, value @ bytecode::Value::Float(_))
+        | (BuiltInTypes::Str, value @ bytecode::Value::String(_)) => Some(value),
+        (BuiltInTypes::Float, bytecode::Value::Int(value)) => {
+            Some(bytecode::Value::Float(value as f32))
+        }
+        (BuiltInTypes::Struct(expected), bytecode::Value::Struct { name, fields })
+            if name == *expected =>
+        {
+            Some(bytecode::Value::Struct { name, fields })
+        }
+        _ => None,
+    }
+}
+
+/// The element of a array, or the char of a str, in a int index
+pub fn index(
+    value: &bytecode::Value,
+    index: &bytecode::Value,
+) -> Result<bytecode::Value, RuntimeErrorKind> {
+    let index = match index {
+        bytecode::Value::Int(index) => *index,
+        _ => {
+            return Err(RuntimeErrorKind::TypeMismatch(format!(
+                "a index must be a int but it's {}",
+                type_name(index)
+            )))
+        }
+    };
+
+    let (element, length) = match value {
+        bytecode::Value::Array(values) => (
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| values.get(index))
+                .cloned(),
+            values.len(),
+        ),
+        bytecode::Value::String(string) => (
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| string.chars().nth(index))
+                .map(bytecode::Value::Char),
+            string.chars().count(),
+        ),
+        _ => {
+            return Err(RuntimeErrorKind::TypeMismatch(format!(
+                "{} can't be indexed",
+                type_name(value)
+            )))
+        }
+    };
+
+    element.ok_or(RuntimeErrorKind::OutOfBounds(index, length))
+}
+
+/// The value of a struct field
+pub fn field(value: &bytecode::Value, field: &str) -> Result<bytecode::Value, RuntimeErrorKind> {
+    match value {
+        bytecode::Value::Struct { name, fields } => fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, value)| value.to_owned())
+            .ok_or(RuntimeErrorKind::UnknownField(
+                name.to_owned(),
+                field.to_string(),
+            )),
+        _ => Err(RuntimeErrorKind::TypeMismatch(format!(
+            "{} has no fields",
+            type_name(value)
+        ))),
+    }
+}
+
+/// Only false and null are false in a condition
+pub fn is_truthy(value: &bytecode::Value) -> bool {
+    !matches!(value, bytecode::Value::Bool(false) | bytecode::Value::Null)
+}
+
+/// Structural equality, a int and a float are equal when they have the same numeric value
+pub fn equals(left: &bytecode::Value, right: &bytecode::Value) -> bool {
+    match (left, right) {
+        (bytecode::Value::Null, bytecode::Value::Null) => true,
+        (bytecode::Value::Bool(left), bytecode::Value::Bool(right)) => left == right,
+        (bytecode::Value::Char(left), bytecode::Value::Char(right)) => left == right,
+        (bytecode::Value::Int(left), bytecode::Value::Int(right)) => left == right,
+        (bytecode::Value::Float(left), bytecode::Value::Float(right)) => left == right,
+        (bytecode::Value::Int(left), bytecode::Value::Float(right))
+        | (bytecode::Value::Float(right), bytecode::Value::Int(left)) => *left as f32 == *right,
+        (bytecode::Value::String(left), bytecode::Value::String(right)) => left == right,
+        (bytecode::Value::Array(left), bytecode::Value::Array(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right.iter())
+                    .all(|(left, right)| equals(left, right))
+        }
+        // Two structs of the same declaration have their fields in the same order
+        (
+            bytecode::Value::Struct {
+                name: left_name,
+                fields: left,
+            },
+            bytecode::Value::Struct {
+                name: right_name,
+                fields: right,
+            },
+        ) => {
+            left_name == right_name
+                && left
+                    .iter()
+                    .zip(right.iter())
+                    .all(|((_, left), (_, right))| equals(left, right))
+        }
+        _ => false,
+    }
+}
+
+/// Order two numbers, two strings or two chars <- NaN isn't ordered, every comparison with it is false
+pub fn compare(
+    left: &bytecode::Value,
+    right: &bytecode::Value,
+) -> Result<Option<Ordering>, RuntimeErrorKind> {
+    match (left, right) {
+        (bytecode::Value::Int(left), bytecode::Value::Int(right)) => Ok(Some(left.cmp(right))),
+        (bytecode::Value::Float(left), bytecode::Value::Float(right)) => {
+            Ok(left.partial_cmp(right))
+        }
+        (bytecode::Value::Int(left), bytecode::Value::Float(right)) => {
+            Ok((*left as f32).partial_cmp(right))
+        }
+        (bytecode::Value::Float(left), bytecode::Value::Int(right)) => {
+            Ok(left.partial_cmp(&(*right as f32)))
+        }
+        (bytecode::Value::String(left), bytecode::Value::String(right)) => {
+            Ok(Some(left.cmp(right)))
+        }
+        (bytecode::Value::Char(left), bytecode::Value::Char(right)) => Ok(Some(left.cmp(right))),
+        _ => Err(RuntimeErrorKind::TypeMismatch(format!(
+            "can't compare {} with {}",
+            type_name(left),
+            type_name(right)
+        ))),
+    }
+}
+
+/// A arithmetic operator, used to share the numeric rules between the instructions
+#[derive(Debug, Clone, Copy)]
+pub enum Arithmetic {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl Arithmetic {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Arithmetic::Add => "+",
+            Arithmetic::Sub => "-",
+            Arithmetic::Mul => "*",
+            Arithmetic::Div => "/",
+            Arithmetic::Mod => "%",
+        }
+    }
+}
+
+/// Apply a arithmetic operator to two values
+///
+/// - Two ints give a int, a overflow is a error instead of wrapping around.
+/// - A int with a float is promoted to float, two floats follow IEEE 754.
+/// - The division of ints truncates toward zero, the rest has the sign of the left operand.
+/// - A division or rest by zero is a error for ints and floats.
+/// - "+" also concatenates two strings.
+pub fn arithmetic(
+    operator: Arithmetic,
+    left: &bytecode::Value,
+    right: &bytecode::Value,
+) -> Result<bytecode::Value, RuntimeErrorKind> {
+    match (left, right) {
+        (bytecode::Value::Int(left), bytecode::Value::Int(right)) => {
+            let result = match operator {
+                Arithmetic::Add => left.checked_add(*right),
+                Arithmetic::Sub => left.checked_sub(*right),
+                Arithmetic::Mul => left.checked_mul(*right),
+                Arithmetic::Div | Arithmetic::Mod if *right == 0 => {
+                    return Err(RuntimeErrorKind::DivisionByZero)
+                }
+                Arithmetic::Div => left.checked_div(*right),
+                Arithmetic::Mod => left.checked_rem(*right),
+            };
+
+            result
+                .map(bytecode::Value::Int)
+                .ok_or(RuntimeErrorKind::IntegerOverflow(format!(
+                    "{} {} {}",
+                    left,
+                    operator.symbol(),
+                    right
+                )))
+        }
+        (bytecode::Value::Int(_) | bytecode::Value::Float(_), bytecode::Value::Float(_))
+        | (bytecode::Value::Float(_), bytecode::Value::Int(_)) => {
+            let left = as_float(left);
+            let right = as_float(right);
+
+            match operator {
+                Arithmetic::Add => Ok(bytecode::Value::Float(left + right)),
+                Arithmetic::Sub => Ok(bytecode::Value::Float(left - right)),
+                Arithmetic::Mul => Ok(bytecode::Value::Float(left * right)),
+                Arithmetic::Div | Arithmetic::Mod if right == 0.0 => {
+                    Err(RuntimeErrorKind::DivisionByZero)
+                }
+                Arithmetic::Div => Ok(bytecode::Value::Float(left / right)),
+                Arithmetic::Mod => Ok(bytecode::Value::Float(left % right)),
+            }
+        }
+        (bytecode::Value::String(left), bytecode::Value::String(right)) => match operator {
+            Arithmetic::Add => Ok(bytecode::Value::String(format!("{}{}", left, right))),
+            _ => Err(RuntimeErrorKind::TypeMismatch(format!(
+                "can't apply \"{}\" to str and str",
+                operator.symbol()
+            ))),
+        },
+        _ => Err(RuntimeErrorKind::TypeMismatch(format!(
+            "can't apply \"{}\" to {} and {}",
+            operator.symbol(),
+            type_name(left),
+            type_name(right)
+        ))),
+    }
+}
+
+/// Negate a number, the negation of the smallest int overflows
+pub fn negate(value: &bytecode::Value) -> Result<bytecode::Value, RuntimeErrorKind> {
+    match value {
+        bytecode::Value::Int(value) => value
+            .checked_neg()
+            .map(bytecode::Value::Int)
+            .ok_or(RuntimeErrorKind::IntegerOverflow(format!("-{}", value))),
+        bytecode::Value::Float(value) => Ok(bytecode::Value::Float(-value)),
+        _ => Err(RuntimeErrorKind::TypeMismatch(format!(
+            "can't negate {}",
+            type_name(value)
+        ))),
+    }
+}
+
+/// Read a int or a float as a float
+fn as_float(value: &bytecode::Value) -> f32 {
+    match value {
+        bytecode::Value::Int(value) => *value as f32,
+        bytecode::Value::Float(value) => *value,
+        _ => f32::NAN,
+    }
+}
@@ -8,18 +8,39 @@ pub fn new_line_callback(lex: &mut logos::Lexer<tokens::TokenType>) {
     lex.extras.1 = lex.span().end;
 }
 
-/// Compute the line and column position for the current word
-pub fn word_callback(lex: &mut logos::Lexer<tokens::TokenType>) {
-    let line = lex.extras.0;
-    let column = lex.span().start - lex.extras.1;
+/// Take the comment until its "#-", update the line count and the char index like new lines do
+pub fn comment_callback(lex: &mut logos::Lexer<tokens::TokenType>) -> bool {
+    let length = match lex.remainder().find("#-") {
+        Some(end) => end + 2,
+        // A comment without end is a invalid token
+        None => return false,
+    };
+    let start = lex.span().end;
+
+    for (index, _) in lex.remainder()[..length].match_indices('\n') {
+        lex.extras.0 += 1;
+        lex.extras.1 = start + index + 1;
+    }
+
+    lex.bump(length);
+    true
 }
 
+/// Words don't need extra work, their position is computed by `Lexer::lex`
+pub fn word_callback(_lex: &mut logos::Lexer<tokens::TokenType>) {}
+
 #[derive(Debug, Clone)]
 pub struct Lexer {
     pub tokens: Vec<tokens::Token>,
     pub errors: Vec<error_handler::Error>,
 }
 
+impl Default for Lexer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Lexer {
     pub fn new() -> Self {
         Self {
@@ -42,6 +63,7 @@ impl Lexer {
                             token_position.line += 1;
                             token_position.column = 0
                         }
+                        tokens::TokenType::Comment => token_position.line = lex.extras.0,
                         _ => token_position.column = lex.span().start - lex.extras.1,
                     }
 
@@ -97,7 +119,7 @@ impl Lexer {
         self.lex_trim_result();
     }
 
-    /// Remove unused spaces and new lines
+    /// Remove unused spaces, new lines and comments
     fn lex_trim_result(&mut self) {
         let mut result: Vec<tokens::Token> = Vec::new();
 
@@ -105,6 +127,7 @@ impl Lexer {
             match token.token_type {
                 tokens::TokenType::NewLine => continue,
                 tokens::TokenType::Space => continue,
+                tokens::TokenType::Comment => continue,
                 _ => result.insert(result.len(), token.to_owned()),
             }
         }
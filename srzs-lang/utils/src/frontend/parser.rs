@@ -7,6 +7,8 @@ pub struct Parser {
     current_token: types::tokens::Token,
     pub errors: Vec<handlers::error_handler::Error>,
     pub ast: types::parse_nodes::Statement,
+    /// If a name followed by "{" is a struct literal <- not in conditions, where "{" starts the body
+    struct_literals: bool,
 }
 
 impl Parser {
@@ -21,15 +23,13 @@ impl Parser {
                 start: types::others::Position::new(0, 0),
                 body: Box::new(Vec::new()),
             },
+            struct_literals: true,
         }
     }
 
     fn push_statement(&mut self, statement: types::parse_nodes::Statement) {
-        match &mut self.ast {
-            types::parse_nodes::Statement::Module { body, .. } => {
-                body.push(statement);
-            }
-            _ => {}
+        if let types::parse_nodes::Statement::Module { body, .. } = &mut self.ast {
+            body.push(statement);
         }
     }
 
@@ -60,9 +60,33 @@ impl Parser {
             types::tokens::TokenType::TypeStr => Ok(types::built_in_types::BuiltInTypes::Str),
             types::tokens::TokenType::TypeVoid => Ok(types::built_in_types::BuiltInTypes::Void),
             types::tokens::TokenType::TypeAny => Ok(types::built_in_types::BuiltInTypes::Any),
+            // A struct name, the current token is the last identifier of its path -> path::Name
+            types::tokens::TokenType::Identifier => {
+                let mut path = self.current().token_value.to_owned();
+
+                while let Some(types::tokens::TokenType::OpAcess) =
+                    self.tokens.peek().map(|token| token.token_type.to_owned())
+                {
+                    // "::"
+                    self.advance();
+                    self.advance();
+
+                    let name = handlers::error_handler::Error::expected_or_error(
+                        "a identifier",
+                        &types::tokens::TokenType::Identifier,
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    )?;
+
+                    path.push_str("::");
+                    path.push_str(&name.token_value);
+                }
+
+                Ok(types::built_in_types::BuiltInTypes::Struct(path))
+            }
             _ => Err(handlers::error_handler::Error::expected_error(
                 "a type",
-                &self.current(),
+                self.current(),
                 handlers::message_handler::Issuer::Parser,
             )),
         }
@@ -97,7 +121,7 @@ impl Parser {
                     self.errors.len(),
                     handlers::error_handler::Error::expected_error(
                         "\"mut\" or a identifier",
-                        &self.current(),
+                        self.current(),
                         handlers::message_handler::Issuer::Parser,
                     ),
                 );
@@ -121,26 +145,57 @@ impl Parser {
 
         self.advance();
 
-        // ":" or ";" after the name
-        match self.current().token_type {
+        // ":", "=", ":=" or ";" after the name
+        let r#type: Option<types::built_in_types::BuiltInTypes> = match self.current().token_type {
             types::tokens::TokenType::Colon => {
                 self.advance();
+
+                // The let statement type
+                let r#type = match self.get_type() {
+                    Ok(r#type) => r#type,
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                self.advance();
+
+                Some(r#type)
+            }
+            types::tokens::TokenType::OpAssign
+            | types::tokens::TokenType::OpInferredTypeAssing
+            | types::tokens::TokenType::SemiColon => None,
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a explicity type, a value or the end of let statement",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        };
+
+        // A expression attribution or the end of statement <- ":=" only when the type is inferred
+        let value = match self.current().token_type {
+            types::tokens::TokenType::OpAssign => {
+                self.advance();
+                Some(self.parse_expression()?)
             }
-            types::tokens::TokenType::SemiColon => {
-                return Some(types::parse_nodes::Statement::LetDeclaration {
-                    start: let_token.position,
-                    name,
-                    kind,
-                    r#type: types::built_in_types::BuiltInTypes::Any,
-                    value: None,
-                });
+            types::tokens::TokenType::OpInferredTypeAssing if r#type.is_none() => {
+                self.advance();
+                Some(self.parse_expression()?)
             }
+            types::tokens::TokenType::SemiColon => None,
             _ => {
                 self.errors.insert(
                     self.errors.len(),
                     handlers::error_handler::Error::expected_error(
-                        "a explicity type or the end of let statement",
-                        &self.current(),
+                        "a value or the end of let statement",
+                        self.current(),
                         handlers::message_handler::Issuer::Parser,
                     ),
                 );
@@ -148,44 +203,460 @@ impl Parser {
             }
         };
 
-        // The let statement type
-        let r#type = match self.get_type() {
-            Ok(r#type) => r#type,
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
             Err(error) => {
                 self.errors.insert(self.errors.len(), error);
                 return None;
             }
         };
 
+        Some(types::parse_nodes::Statement::LetDeclaration {
+            start: let_token.position,
+            public: false,
+            name,
+            kind,
+            r#type,
+            value,
+        })
+    }
+
+    /// Return the binding power of a binary operator, or nothing if the token isn't one
+    fn binary_precedence(token_type: &types::tokens::TokenType) -> Option<u8> {
+        match token_type {
+            types::tokens::TokenType::OpOr => Some(1),
+            types::tokens::TokenType::OpAnd => Some(2),
+            types::tokens::TokenType::OpEquals | types::tokens::TokenType::OpNotEquals => Some(3),
+            types::tokens::TokenType::OpSmallerThan
+            | types::tokens::TokenType::OpGreaterThan
+            | types::tokens::TokenType::OpSmallerOrEqualsThan
+            | types::tokens::TokenType::OpGreaterOrEqualsThan => Some(4),
+            types::tokens::TokenType::OpPlus | types::tokens::TokenType::OpMinus => Some(5),
+            types::tokens::TokenType::OpMultiply
+            | types::tokens::TokenType::OpDivision
+            | types::tokens::TokenType::OpRest => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Parse and return a expression, the current token will be the first one after it
+    fn parse_expression(&mut self) -> Option<types::parse_nodes::Expression> {
+        self.parse_binary_expression(0)
+    }
+
+    /// Parse a expression that can or can't have struct literals outside of delimiters
+    fn parse_expression_with(
+        &mut self,
+        struct_literals: bool,
+    ) -> Option<types::parse_nodes::Expression> {
+        let previous = std::mem::replace(&mut self.struct_literals, struct_literals);
+        let expression = self.parse_expression();
+        self.struct_literals = previous;
+
+        expression
+    }
+
+    /// Parse a condition followed by a body -> a struct literal must be between parentheses there
+    fn parse_condition(&mut self) -> Option<types::parse_nodes::Expression> {
+        self.parse_expression_with(false)
+    }
+
+    /// Parse binary operators with a binding power greater than `min_precedence` <- left associative
+    fn parse_binary_expression(
+        &mut self,
+        min_precedence: u8,
+    ) -> Option<types::parse_nodes::Expression> {
+        let mut left = self.parse_unary_expression()?;
+
+        while let Some(precedence) = Parser::binary_precedence(self.current_type()) {
+            if precedence <= min_precedence {
+                break;
+            }
+
+            // The operator
+            let operator = self.current_type().to_owned();
+            self.advance();
+
+            // The right side only takes operators that bind tighter than this one
+            let right = self.parse_binary_expression(precedence)?;
+
+            left = match operator {
+                types::tokens::TokenType::OpAnd | types::tokens::TokenType::OpOr => {
+                    types::parse_nodes::Expression::Logical {
+                        operator,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    }
+                }
+                _ => types::parse_nodes::Expression::Binary {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+            };
+        }
+
+        Some(left)
+    }
+
+    /// Parse a unary expression -> -operand, not operand, !operand
+    fn parse_unary_expression(&mut self) -> Option<types::parse_nodes::Expression> {
+        match self.current_type() {
+            types::tokens::TokenType::OpMinus | types::tokens::TokenType::OpNot => {
+                let operator = self.current_type().to_owned();
+                self.advance();
+
+                let operand = self.parse_unary_expression()?;
+
+                Some(types::parse_nodes::Expression::Unary {
+                    operator,
+                    operand: Box::new(operand),
+                })
+            }
+            _ => self.parse_postfix_expression(),
+        }
+    }
+
+    /// Parse the field acesses and method calls after a primary expression -> expression.field.method()
+    fn parse_postfix_expression(&mut self) -> Option<types::parse_nodes::Expression> {
+        let mut expression = self.parse_primary_expression()?;
+
+        while self.peek_expect(&types::tokens::TokenType::Dot) {
+            self.advance();
+
+            // "." <- identifier
+            let field = match handlers::error_handler::Error::expected_or_error(
+                "a field name",
+                &types::tokens::TokenType::Identifier,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(field) => field.token_value,
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            // expression.method"(" <- A method call
+            if self.peek_expect(&types::tokens::TokenType::LParen) {
+                self.advance();
+
+                let arguments =
+                    self.parse_expression_list("')'", &types::tokens::TokenType::RParen)?;
+
+                // ")"
+                self.advance();
+
+                expression = types::parse_nodes::Expression::MethodCall {
+                    object: Box::new(expression),
+                    method: field,
+                    arguments: match arguments.is_empty() {
+                        true => None,
+                        false => Some(Box::new(arguments)),
+                    },
+                };
+                continue;
+            }
+
+            expression = types::parse_nodes::Expression::FieldAcess {
+                object: Box::new(expression),
+                field,
+            };
+        }
+
+        Some(expression)
+    }
+
+    /// Parse a comma separated list of expressions until the `end` token <- a, b, c
+    fn parse_expression_list(
+        &mut self,
+        expected_end: &str,
+        end: &types::tokens::TokenType,
+    ) -> Option<Vec<types::parse_nodes::Expression>> {
+        let mut expressions: Vec<types::parse_nodes::Expression> = vec![];
+
+        // An empty list
+        if self.peek_expect(end) {
+            return Some(expressions);
+        }
+
+        loop {
+            expressions.push(self.parse_expression_with(true)?);
+
+            // Another expression or the end of the list
+            if self.peek_expect(&types::tokens::TokenType::Comma) {
+                self.advance();
+            } else if self.peek_expect(end) {
+                break;
+            } else {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        format!("',' or {}", expected_end).as_str(),
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        }
+
+        Some(expressions)
+    }
+
+    /// Parse a identifier and the "::" separated identifiers after it into one name
+    fn parse_path(&mut self) -> Option<String> {
+        let mut path = self.current().token_value.to_owned();
         self.advance();
 
-        // A expression attribution or the end of statement
-        match self.current().token_type {
-            types::tokens::TokenType::SemiColon => {
-                return Some(types::parse_nodes::Statement::LetDeclaration {
-                    start: let_token.position,
-                    name,
-                    kind,
-                    r#type,
-                    value: None,
-                });
+        // path"::"identifier
+        while self.peek_expect(&types::tokens::TokenType::OpAcess) {
+            self.advance();
+
+            match handlers::error_handler::Error::expected_or_error(
+                "a identifier",
+                &types::tokens::TokenType::Identifier,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(_) => {}
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            path.push_str("::");
+            path.push_str(&self.current().token_value);
+            self.advance();
+        }
+
+        Some(path)
+    }
+
+    /// Parse a literal, a variable, a call, a struct, an array or a expression between parentheses
+    fn parse_primary_expression(&mut self) -> Option<types::parse_nodes::Expression> {
+        match self.current_type() {
+            types::tokens::TokenType::Number
+            | types::tokens::TokenType::DecimalNumber
+            | types::tokens::TokenType::StringLiteral
+            | types::tokens::TokenType::CharLiteral
+            | types::tokens::TokenType::True
+            | types::tokens::TokenType::False
+            | types::tokens::TokenType::TypeNull => {
+                let literal = self.current().to_owned();
+                self.advance();
+
+                Some(types::parse_nodes::Expression::Literal {
+                    r#type: literal.token_type,
+                    value: literal.token_value,
+                })
+            }
+            types::tokens::TokenType::Identifier => {
+                let name = self.parse_path()?;
+                let struct_literals = self.struct_literals;
+
+                match self.current_type() {
+                    // name"(" <- A function call
+                    types::tokens::TokenType::LParen => {
+                        self.advance();
+
+                        let arguments =
+                            self.parse_expression_list("')'", &types::tokens::TokenType::RParen)?;
+
+                        // ")"
+                        self.advance();
+
+                        Some(types::parse_nodes::Expression::Call {
+                            name,
+                            arguments: match arguments.is_empty() {
+                                true => None,
+                                false => Some(Box::new(arguments)),
+                            },
+                        })
+                    }
+                    // name"[" <- A array acess
+                    types::tokens::TokenType::LBrace => {
+                        let mut acess: Option<types::parse_nodes::ArrayAcess> = None;
+
+                        while self.peek_expect(&types::tokens::TokenType::LBrace) {
+                            self.advance();
+
+                            let index = Box::new(self.parse_expression_with(true)?);
+
+                            // "]"
+                            match handlers::error_handler::Error::expected_or_error(
+                                "']'",
+                                &types::tokens::TokenType::RBrace,
+                                self.current(),
+                                handlers::message_handler::Issuer::Parser,
+                            ) {
+                                Ok(_) => {}
+                                Err(error) => {
+                                    self.errors.insert(self.errors.len(), error);
+                                    return None;
+                                }
+                            };
+
+                            self.advance();
+
+                            acess = Some(match acess {
+                                None => types::parse_nodes::ArrayAcess::Acess {
+                                    name: name.to_owned(),
+                                    index,
+                                },
+                                Some(acess) => types::parse_nodes::ArrayAcess::NestedAcess {
+                                    acess: Box::new(acess),
+                                    index,
+                                },
+                            });
+                        }
+
+                        acess.map(types::parse_nodes::Expression::ArrayAcess)
+                    }
+                    // name"{" <- A struct literal
+                    types::tokens::TokenType::LBracket if struct_literals => {
+                        self.parse_struct_literal(name)
+                    }
+                    _ => Some(types::parse_nodes::Expression::Identifier(name)),
+                }
+            }
+            // "(" expression ")"
+            types::tokens::TokenType::LParen => {
+                self.advance();
+
+                let expression = self.parse_expression_with(true)?;
+
+                match handlers::error_handler::Error::expected_or_error(
+                    "')'",
+                    &types::tokens::TokenType::RParen,
+                    self.current(),
+                    handlers::message_handler::Issuer::Parser,
+                ) {
+                    Ok(_) => {}
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                self.advance();
+
+                Some(expression)
+            }
+            // "[" elements "]"
+            types::tokens::TokenType::LBrace => {
+                self.advance();
+
+                let elements =
+                    self.parse_expression_list("']'", &types::tokens::TokenType::RBrace)?;
+
+                // "]"
+                self.advance();
+
+                Some(types::parse_nodes::Expression::ArrayLiteral {
+                    elements: match elements.is_empty() {
+                        true => None,
+                        false => Some(Box::new(elements)),
+                    },
+                })
             }
             _ => {
                 self.errors.insert(
                     self.errors.len(),
                     handlers::error_handler::Error::expected_error(
-                        "a explicity type or the end of let statement",
-                        &self.current(),
+                        "a expression",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// Parse the fields of a struct literal, the current token is its "{" -> Name { field: expression, ... }
+    fn parse_struct_literal(&mut self, name: String) -> Option<types::parse_nodes::Expression> {
+        // "{"
+        self.advance();
+
+        let mut fields: Vec<(String, types::parse_nodes::Expression)> = vec![];
+
+        while !self.peek_expect(&types::tokens::TokenType::RBracket) {
+            // "field": expression
+            let field = match handlers::error_handler::Error::expected_or_error(
+                "a field name or '}'",
+                &types::tokens::TokenType::Identifier,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(field) => field.token_value,
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            // field ":" expression
+            match handlers::error_handler::Error::expected_or_error(
+                "':'",
+                &types::tokens::TokenType::Colon,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(_) => {}
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            fields.push((field, self.parse_expression_with(true)?));
+
+            // Another field or the end of the literal <- a trailing "," is allowed
+            if self.peek_expect(&types::tokens::TokenType::Comma) {
+                self.advance();
+            } else if !self.peek_expect(&types::tokens::TokenType::RBracket) {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "',' or '}'",
+                        self.current(),
                         handlers::message_handler::Issuer::Parser,
                     ),
                 );
                 return None;
             }
-        };
+        }
+
+        // "}"
+        self.advance();
+
+        Some(types::parse_nodes::Expression::StructLiteral {
+            name,
+            fields: Box::new(fields),
+        })
     }
 
     /// Parse and return a paremeter statement vector <- (param_name: type, ...)
-    fn parse_params_statement(&mut self) -> Option<Vec<types::parse_nodes::FuncParam>> {
+    /// The first parameter of a method can be a "self" without type, it's a value of the method struct
+    fn parse_params_statement(
+        &mut self,
+        self_type: Option<&types::built_in_types::BuiltInTypes>,
+    ) -> Option<Vec<types::parse_nodes::FuncParam>> {
         // "("
         self.advance();
 
@@ -218,33 +689,47 @@ impl Parser {
 
             self.advance();
 
-            // param_name ":" type <- ":" after the identifier
-            match handlers::error_handler::Error::expected_or_error(
-                "Colon",
-                &types::tokens::TokenType::Colon,
-                self.current(),
-                handlers::message_handler::Issuer::Parser,
-            ) {
-                Ok(_) => {}
-                Err(error) => {
-                    self.errors.insert(self.errors.len(), error);
-                    return None;
+            let r#type = match self_type {
+                // "self" <- The type is the struct of the method
+                Some(self_type)
+                    if params.is_empty()
+                        && name == "self"
+                        && !self.peek_expect(&types::tokens::TokenType::Colon) =>
+                {
+                    self_type.to_owned()
                 }
-            };
+                _ => {
+                    // param_name ":" type <- ":" after the identifier
+                    match handlers::error_handler::Error::expected_or_error(
+                        "Colon",
+                        &types::tokens::TokenType::Colon,
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ) {
+                        Ok(_) => {}
+                        Err(error) => {
+                            self.errors.insert(self.errors.len(), error);
+                            return None;
+                        }
+                    };
 
-            self.advance();
+                    self.advance();
 
-            // param_name: "type" <- The type of the parameter
-            let r#type = match self.get_type() {
-                Ok(r#type) => r#type,
-                Err(error) => {
-                    self.errors.insert(self.errors.len(), error);
-                    return None;
+                    // param_name: "type" <- The type of the parameter
+                    let r#type = match self.get_type() {
+                        Ok(r#type) => r#type,
+                        Err(error) => {
+                            self.errors.insert(self.errors.len(), error);
+                            return None;
+                        }
+                    };
+
+                    self.advance();
+
+                    r#type
                 }
             };
 
-            self.advance();
-
             // The glue
             param = types::parse_nodes::FuncParam { name, r#type };
 
@@ -294,14 +779,9 @@ impl Parser {
 
         // Block content
         let mut block_statements: Vec<types::parse_nodes::Statement> = vec![];
-        let mut current_statement: types::parse_nodes::Statement;
 
-        while !self.peek_expect(&types::tokens::TokenType::RBracket)
-            || !self.peek_expect(&types::tokens::TokenType::Eof)
-        {
-            if self.peek_expect(&types::tokens::TokenType::RBracket) {
-                break;
-            } else if self.peek_expect(&types::tokens::TokenType::Eof) {
+        while !self.peek_expect(&types::tokens::TokenType::RBracket) {
+            if self.peek_expect(&types::tokens::TokenType::Eof) {
                 self.errors.insert(
                     self.errors.len(),
                     handlers::error_handler::Error::expected_error(
@@ -312,6 +792,12 @@ impl Parser {
                 );
                 return None;
             }
+
+            // The statements inside a loop body can break or continue it
+            block_statements.push(self.parse_statement(is_loop)?);
+
+            // The last token of the statement
+            self.advance();
         }
 
         match handlers::error_handler::Error::expected_or_error(
@@ -330,8 +816,465 @@ impl Parser {
         Some(block_statements)
     }
 
-    /// Parse and return a function statement -> fn function_name(param_name: type, ...) -> type {...}
+    /// Parse and return a return statement -> return expression; or return;
+    fn parse_return_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "return" token <- used to get the start of the statement
+        let return_token = self.current().to_owned();
+        self.advance();
+
+        // The returned value is optional
+        let expression = match self.peek_expect(&types::tokens::TokenType::SemiColon) {
+            true => None,
+            false => Some(self.parse_expression()?),
+        };
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        Some(types::parse_nodes::Statement::Return {
+            start: return_token.position,
+            expression,
+        })
+    }
+
+    /// Parse and return a break or continue statement, only valid inside a loop -> break; or continue;
+    fn parse_loop_control_statement(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<types::parse_nodes::Statement> {
+        // "break" or "continue" token <- used to get the start of the statement
+        let control_token = self.current().to_owned();
+
+        if let types::parse_nodes::Loop::No = is_loop {
+            self.errors.insert(
+                self.errors.len(),
+                handlers::error_handler::Error::script_error(
+                    format!(
+                        "\"{}\" can only be used inside a loop.",
+                        control_token.token_value
+                    )
+                    .as_str(),
+                    handlers::error_handler::ErrorCode::E007,
+                    &control_token.position,
+                    handlers::message_handler::Issuer::Parser,
+                ),
+            );
+            return None;
+        }
+
+        self.advance();
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        match control_token.token_type {
+            types::tokens::TokenType::KwBreak => Some(types::parse_nodes::Statement::Break {
+                start: control_token.position,
+            }),
+            _ => Some(types::parse_nodes::Statement::Continue {
+                start: control_token.position,
+            }),
+        }
+    }
+
+    /// If the token type is "=" or a compound assignment operator return true, else return false
+    fn is_assignment_operator(token_type: &types::tokens::TokenType) -> bool {
+        matches!(
+            token_type,
+            types::tokens::TokenType::OpAssign
+                | types::tokens::TokenType::OpAssignPlus
+                | types::tokens::TokenType::OpAssignMinus
+                | types::tokens::TokenType::OpAssignMultiply
+                | types::tokens::TokenType::OpAssignDivision
+                | types::tokens::TokenType::OpAssignRest
+        )
+    }
+
+    /// Parse and return a assignment without the end of statement -> name = expression or name.field = expression
+    fn parse_assignment(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "name" <- The altered variable
+        let name_token = match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(name_token) => name_token,
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        // A variable or a field of it
+        match self.parse_postfix_expression()? {
+            types::parse_nodes::Expression::Identifier(name) => {
+                self.parse_assignment_value(name_token.position, name)
+            }
+            target @ types::parse_nodes::Expression::FieldAcess { .. } => {
+                self.parse_field_assignment(name_token.position, target)
+            }
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a assignment",
+                        &name_token,
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// Parse the operator and the value of a assignment to a name
+    fn parse_assignment_value(
+        &mut self,
+        start: types::others::Position,
+        name: String,
+    ) -> Option<types::parse_nodes::Statement> {
+        let (operator, value) = self.parse_assignment_operation()?;
+
+        Some(types::parse_nodes::Statement::VariableAlteration {
+            start,
+            name,
+            operator,
+            value,
+        })
+    }
+
+    /// Parse a assignment operator and the new value after it -> = expression
+    fn parse_assignment_operation(
+        &mut self,
+    ) -> Option<(types::tokens::TokenType, types::parse_nodes::Expression)> {
+        // The assignment operator
+        let operator = self.current_type().to_owned();
+
+        if !Parser::is_assignment_operator(&operator) {
+            self.errors.insert(
+                self.errors.len(),
+                handlers::error_handler::Error::expected_error(
+                    "a assignment operator",
+                    self.current(),
+                    handlers::message_handler::Issuer::Parser,
+                ),
+            );
+            return None;
+        }
+
+        self.advance();
+
+        // The new value
+        let value = self.parse_expression()?;
+
+        Some((operator, value))
+    }
+
+    /// Parse and return a assignment statement -> name = expression; or name += expression; ...
+    fn parse_assignment_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        let assignment = self.parse_assignment()?;
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        Some(assignment)
+    }
+
+    /// Parse and return a while statement -> while condition {...}
+    fn parse_while_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "while" token <- used to get the start of the statement
+        let while_token = self.current().to_owned();
+        self.advance();
+
+        let (condition, body) = self.parse_conditional_body(&types::parse_nodes::Loop::Yes)?;
+
+        Some(types::parse_nodes::Statement::While {
+            start: while_token.position,
+            condition,
+            body: match body.is_empty() {
+                true => None,
+                false => Some(Box::new(body)),
+            },
+        })
+    }
+
+    /// Parse and return a for statement -> for let mut i = 0; i < 10; i += 1 {...} else {...}
+    /// Every part before the body is optional, the else body runs when the loop ends without a break
+    fn parse_for_statement(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<types::parse_nodes::Statement> {
+        // "for" token <- used to get the start of the statement
+        let for_token = self.current().to_owned();
+        self.advance();
+
+        // The loop variable, a let or a assignment statement that ends with ";"
+        let variable = match self.current_type() {
+            types::tokens::TokenType::SemiColon => None,
+            types::tokens::TokenType::KwLet => Some(Box::new(self.parse_let_statement()?)),
+            _ => Some(Box::new(self.parse_assignment_statement()?)),
+        };
+
+        // ";"
+        self.advance();
+
+        // The condition <- without a condition the loop only ends with a break
+        let condition = match self.current_type() {
+            types::tokens::TokenType::SemiColon => None,
+            _ => Some(self.parse_condition()?),
+        };
+
+        // ";" <- End of the condition
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // The variable update, a assignment without ";" <- the body comes after its value
+        let variable_update = match self.current_type() {
+            types::tokens::TokenType::LBracket => None,
+            _ => {
+                let struct_literals = std::mem::replace(&mut self.struct_literals, false);
+                let variable_update = self.parse_assignment();
+                self.struct_literals = struct_literals;
+
+                Some(Box::new(variable_update?))
+            }
+        };
+
+        // "{" <- Start of the body
+        match handlers::error_handler::Error::expected_or_error(
+            "'{'",
+            &types::tokens::TokenType::LBracket,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        let body = self.parse_block_statement(&types::parse_nodes::Loop::Yes)?;
+
+        // "else" after the body <- break and continue refer to the outer loop inside it
+        let alternate = match self.tokens.peek().map(|token| token.token_type.to_owned()) {
+            Some(types::tokens::TokenType::KwElse) => {
+                // "}" -> "else"
+                self.advance();
+                self.advance();
+
+                // "{" <- Start of the else body
+                match handlers::error_handler::Error::expected_or_error(
+                    "'{'",
+                    &types::tokens::TokenType::LBracket,
+                    self.current(),
+                    handlers::message_handler::Issuer::Parser,
+                ) {
+                    Ok(_) => {}
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                let alternate = self.parse_block_statement(is_loop)?;
+
+                match alternate.is_empty() {
+                    true => None,
+                    false => Some(Box::new(alternate)),
+                }
+            }
+            _ => None,
+        };
+
+        Some(types::parse_nodes::Statement::For {
+            start: for_token.position,
+            variable,
+            condition,
+            variable_update,
+            body: match body.is_empty() {
+                true => None,
+                false => Some(Box::new(body)),
+            },
+            alternate,
+        })
+    }
+
+    /// Parse and return a if statement -> if condition {...} elif condition {...} else {...}
+    fn parse_if_statement(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<types::parse_nodes::Statement> {
+        // "if" token <- used to get the start of the statement
+        let if_token = self.current().to_owned();
+        self.advance();
+
+        let (condition, body) = self.parse_conditional_body(is_loop)?;
+
+        // "elif" or "else" after the body <- The alternate chain is optional
+        let alternate = self.parse_alternate_statement(is_loop)?;
+
+        Some(types::parse_nodes::Statement::If {
+            start: if_token.position,
+            condition,
+            body: match body.is_empty() {
+                true => None,
+                false => Some(Box::new(body)),
+            },
+            alternate,
+        })
+    }
+
+    /// Parse the condition and the body of a if or elif statement -> condition {...}
+    fn parse_conditional_body(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<(
+        types::parse_nodes::Expression,
+        Vec<types::parse_nodes::Statement>,
+    )> {
+        // The condition
+        let condition = self.parse_condition()?;
+
+        // "{" <- Start of the body
+        match handlers::error_handler::Error::expected_or_error(
+            "'{'",
+            &types::tokens::TokenType::LBracket,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        let body = self.parse_block_statement(is_loop)?;
+
+        Some((condition, body))
+    }
+
+    /// Parse the "elif" and "else" chain after a if body, each elif is nested in the previous alternate
+    fn parse_alternate_statement(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<Option<Box<Vec<types::parse_nodes::Statement>>>> {
+        // The current token is the "}" of the previous body, so look at the next one
+        match self.tokens.peek().map(|token| token.token_type.to_owned()) {
+            Some(types::tokens::TokenType::KwElif) => {
+                // "}" -> "elif"
+                self.advance();
+
+                let elif_token = self.current().to_owned();
+                self.advance();
+
+                let (condition, body) = self.parse_conditional_body(is_loop)?;
+                let alternate = self.parse_alternate_statement(is_loop)?;
+
+                Some(Some(Box::new(vec![
+                    types::parse_nodes::Statement::ElseIf {
+                        start: elif_token.position,
+                        condition,
+                        body: match body.is_empty() {
+                            true => None,
+                            false => Some(Box::new(body)),
+                        },
+                        alternate,
+                    },
+                ])))
+            }
+            Some(types::tokens::TokenType::KwElse) => {
+                // "}" -> "else"
+                self.advance();
+
+                let else_token = self.current().to_owned();
+                self.advance();
+
+                // "{" <- Start of the body
+                match handlers::error_handler::Error::expected_or_error(
+                    "'{'",
+                    &types::tokens::TokenType::LBracket,
+                    self.current(),
+                    handlers::message_handler::Issuer::Parser,
+                ) {
+                    Ok(_) => {}
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                let body = self.parse_block_statement(is_loop)?;
+
+                Some(Some(Box::new(vec![types::parse_nodes::Statement::Else {
+                    start: else_token.position,
+                    body: match body.is_empty() {
+                        true => None,
+                        false => Some(Box::new(body)),
+                    },
+                }])))
+            }
+            _ => Some(None),
+        }
+    }
+
+    /// Parse and return a function statement -> fn function_name(param_name: type, ...) -> type {...}
     fn parse_function_satement(&mut self) -> Option<types::parse_nodes::Statement> {
+        self.parse_function(None)
+    }
+
+    /// Parse a function, a method has the type of its struct to give to "self"
+    fn parse_function(
+        &mut self,
+        self_type: Option<&types::built_in_types::BuiltInTypes>,
+    ) -> Option<types::parse_nodes::Statement> {
         // "fn" token <- used to get the start of the statement
         let fn_token = self.current().to_owned();
         self.advance();
@@ -367,10 +1310,7 @@ impl Parser {
         };
 
         // Function parameters
-        let params = match self.parse_params_statement() {
-            Some(params) => params,
-            None => return None,
-        };
+        let params = self.parse_params_statement(self_type)?;
 
         // ")" <- End of function parameters
         self.advance();
@@ -424,13 +1364,11 @@ impl Parser {
             }
         };
 
-        let body = match self.parse_block_statement(&types::parse_nodes::Loop::No) {
-            Some(body) => body,
-            None => return None,
-        };
+        let body = self.parse_block_statement(&types::parse_nodes::Loop::No)?;
 
         Some(types::parse_nodes::Statement::FuctionDeclaration {
             start: fn_token.position,
+            public: false,
             name,
             r#type,
             params,
@@ -438,26 +1376,611 @@ impl Parser {
         })
     }
 
-    /// While doesn't reaches EOF parse tokens
-    pub fn parse_tokens(&mut self) {
-        while !self.peek_expect(&types::tokens::TokenType::Eof) {
-            // Parse and return a statement
-            let ast_node: Option<types::parse_nodes::Statement> = match &self.current_type() {
-                types::tokens::TokenType::KwFn => self.parse_function_satement(),
-                types::tokens::TokenType::KwLet => self.parse_let_statement(),
+    /// Parse the operator and the value of a assignment to a field of a variable -> name.field.field = expression
+    fn parse_field_assignment(
+        &mut self,
+        start: types::others::Position,
+        target: types::parse_nodes::Expression,
+    ) -> Option<types::parse_nodes::Statement> {
+        // The path of fields, from the outermost acess to the variable
+        let mut fields: Vec<String> = vec![];
+        let mut object = target;
+
+        let name = loop {
+            match object {
+                types::parse_nodes::Expression::FieldAcess {
+                    object: inner,
+                    field,
+                } => {
+                    fields.insert(0, field);
+                    object = *inner;
+                }
+                types::parse_nodes::Expression::Identifier(name) => break name,
                 _ => {
-                    handlers::error_handler::Error::expected_error(
-                        "a statement",
-                        self.current(),
-                        handlers::message_handler::Issuer::Parser,
+                    self.errors.insert(
+                        self.errors.len(),
+                        handlers::error_handler::Error::script_error(
+                            "only the fields of a variable can be assigned.",
+                            handlers::error_handler::ErrorCode::E002,
+                            &start,
+                            handlers::message_handler::Issuer::Parser,
+                        ),
                     );
-                    break;
+                    return None;
+                }
+            }
+        };
+
+        let (operator, value) = self.parse_assignment_operation()?;
+
+        Some(types::parse_nodes::Statement::FieldAlteration {
+            start,
+            name,
+            fields,
+            operator,
+            value,
+        })
+    }
+
+    /// Parse and return a statement that starts with a name -> a function call or a assignment
+    fn parse_name_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        let name_token = self.current().to_owned();
+        let expression = self.parse_expression()?;
+
+        let statement = match expression {
+            types::parse_nodes::Expression::Identifier(name)
+                if Parser::is_assignment_operator(self.current_type()) =>
+            {
+                self.parse_assignment_value(name_token.position, name)?
+            }
+            types::parse_nodes::Expression::FieldAcess { .. }
+                if Parser::is_assignment_operator(self.current_type()) =>
+            {
+                self.parse_field_assignment(name_token.position, expression)?
+            }
+            types::parse_nodes::Expression::Call { .. }
+            | types::parse_nodes::Expression::MethodCall { .. } => {
+                types::parse_nodes::Statement::FunctionCall {
+                    start: name_token.position,
+                    expression,
                 }
+            }
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a function call or a assignment",
+                        &name_token,
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        };
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        Some(statement)
+    }
+
+    /// Parse and return a constant statement -> const NAME: type = expression; or const NAME := expression;
+    fn parse_const_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "const" token <- used to get the start of the statement
+        let const_token = self.current().to_owned();
+        self.advance();
+
+        // The constant name
+        let name = match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(name) => name.token_value,
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // ":" type "=" or ":=" <- a constant always has a value
+        let r#type: Option<types::built_in_types::BuiltInTypes> = match self.current().token_type {
+            types::tokens::TokenType::Colon => {
+                self.advance();
+
+                let r#type = match self.get_type() {
+                    Ok(r#type) => r#type,
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                self.advance();
+
+                match handlers::error_handler::Error::expected_or_error(
+                    "'='",
+                    &types::tokens::TokenType::OpAssign,
+                    self.current(),
+                    handlers::message_handler::Issuer::Parser,
+                ) {
+                    Ok(_) => {}
+                    Err(error) => {
+                        self.errors.insert(self.errors.len(), error);
+                        return None;
+                    }
+                };
+
+                Some(r#type)
+            }
+            types::tokens::TokenType::OpAssign | types::tokens::TokenType::OpInferredTypeAssing => {
+                None
+            }
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a explicity type or a value",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        };
+
+        self.advance();
+
+        let value = self.parse_expression()?;
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        Some(types::parse_nodes::Statement::ConstantDeclaration {
+            start: const_token.position,
+            public: false,
+            name: Some(name),
+            r#type,
+            value,
+        })
+    }
+
+    /// Parse and return a struct statement -> struct Name { field: type, ... }
+    fn parse_struct_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "struct" token <- used to get the start of the statement
+        let struct_token = self.current().to_owned();
+        self.advance();
+
+        // The struct name
+        let name = match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(name) => name.token_value,
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // "{" <- Start of the fields
+        match handlers::error_handler::Error::expected_or_error(
+            "'{'",
+            &types::tokens::TokenType::LBracket,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        let mut fields: Vec<types::parse_nodes::StructField> = vec![];
+
+        while !self.peek_expect(&types::tokens::TokenType::RBracket) {
+            // "field": type
+            let field = match handlers::error_handler::Error::expected_or_error(
+                "a field name or '}'",
+                &types::tokens::TokenType::Identifier,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(field) => field.token_value,
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            // field ":" type
+            match handlers::error_handler::Error::expected_or_error(
+                "':'",
+                &types::tokens::TokenType::Colon,
+                self.current(),
+                handlers::message_handler::Issuer::Parser,
+            ) {
+                Ok(_) => {}
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            let r#type = match self.get_type() {
+                Ok(r#type) => r#type,
+                Err(error) => {
+                    self.errors.insert(self.errors.len(), error);
+                    return None;
+                }
+            };
+
+            self.advance();
+
+            fields.push(types::parse_nodes::StructField {
+                name: field,
+                r#type,
+            });
+
+            // Another field or the end of the struct <- a trailing "," is allowed
+            if self.peek_expect(&types::tokens::TokenType::Comma) {
+                self.advance();
+            } else if !self.peek_expect(&types::tokens::TokenType::RBracket) {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "',' or '}'",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        }
+
+        Some(types::parse_nodes::Statement::StructDeclaration {
+            start: struct_token.position,
+            public: false,
+            name,
+            fields,
+        })
+    }
+
+    /// Parse and return a impl statement -> impl Name { fn method(self, ...) {...} ... }
+    fn parse_impl_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "impl" token <- used to get the start of the statement
+        let impl_token = self.current().to_owned();
+        self.advance();
+
+        // "impl" <- identifier
+        match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        let name = self.parse_path()?;
+        let self_type = types::built_in_types::BuiltInTypes::Struct(name.to_owned());
+
+        // path <- "{"
+        match handlers::error_handler::Error::expected_or_error(
+            "'{'",
+            &types::tokens::TokenType::LBracket,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // Only functions can be inside a impl
+        let mut methods: Vec<types::parse_nodes::Statement> = vec![];
+
+        while !self.peek_expect(&types::tokens::TokenType::RBracket) {
+            let public = self.peek_expect(&types::tokens::TokenType::KwPub);
+
+            if public {
+                self.advance();
+            }
+
+            let method = match self.current_type() {
+                types::tokens::TokenType::KwFn => self.parse_function(Some(&self_type)),
+                _ => {
+                    self.errors.insert(
+                        self.errors.len(),
+                        handlers::error_handler::Error::expected_error(
+                            "a method or '}'",
+                            self.current(),
+                            handlers::message_handler::Issuer::Parser,
+                        ),
+                    );
+                    None
+                }
+            };
+
+            let mut method = method?;
+
+            if let types::parse_nodes::Statement::FuctionDeclaration {
+                public: is_public, ..
+            } = &mut method
+            {
+                *is_public = public;
+            }
+
+            methods.push(method);
+
+            // The "}" of the method
+            self.advance();
+        }
+
+        Some(types::parse_nodes::Statement::Impl {
+            start: impl_token.position,
+            name,
+            methods: Box::new(methods),
+        })
+    }
+
+    /// Parse and return a use statement -> use path::to::item;
+    fn parse_use_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "use" token <- used to get the start of the statement
+        let use_token = self.current().to_owned();
+        self.advance();
+
+        // "use" <- identifier
+        match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        let path = self.parse_path()?;
+
+        // ";" <- End of the statement
+        match handlers::error_handler::Error::expected_or_error(
+            "';'",
+            &types::tokens::TokenType::SemiColon,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        Some(types::parse_nodes::Statement::Use {
+            start: use_token.position,
+            path,
+        })
+    }
+
+    /// Parse and return a item that can be used from other modules -> pub fn, pub let, pub struct, pub ns
+    fn parse_public_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "pub"
+        self.advance();
+
+        let mut item = match self.current_type() {
+            types::tokens::TokenType::KwFn => self.parse_function_satement()?,
+            types::tokens::TokenType::KwLet => self.parse_let_statement()?,
+            types::tokens::TokenType::KwConst => self.parse_const_statement()?,
+            types::tokens::TokenType::KwStruct => self.parse_struct_statement()?,
+            types::tokens::TokenType::KwNs => self.parse_namespace_statement()?,
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a function, a variable, a constant, a struct or a namespace",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                return None;
+            }
+        };
+
+        match &mut item {
+            types::parse_nodes::Statement::FuctionDeclaration { public, .. }
+            | types::parse_nodes::Statement::LetDeclaration { public, .. }
+            | types::parse_nodes::Statement::ConstantDeclaration { public, .. }
+            | types::parse_nodes::Statement::StructDeclaration { public, .. }
+            | types::parse_nodes::Statement::Namespace { public, .. } => *public = true,
+            _ => {}
+        }
+
+        Some(item)
+    }
+
+    /// Parse and return a namespace statement -> ns name { items }
+    fn parse_namespace_statement(&mut self) -> Option<types::parse_nodes::Statement> {
+        // "ns" token <- used to get the start of the statement
+        let ns_token = self.current().to_owned();
+        self.advance();
+
+        // "ns" <- identifier
+        match handlers::error_handler::Error::expected_or_error(
+            "a identifier",
+            &types::tokens::TokenType::Identifier,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        let name = self.current().token_value.to_owned();
+        self.advance();
+
+        // identifier <- "{"
+        match handlers::error_handler::Error::expected_or_error(
+            "'{'",
+            &types::tokens::TokenType::LBracket,
+            self.current(),
+            handlers::message_handler::Issuer::Parser,
+        ) {
+            Ok(_) => {}
+            Err(error) => {
+                self.errors.insert(self.errors.len(), error);
+                return None;
+            }
+        };
+
+        self.advance();
+
+        // Only declarations can be inside a namespace
+        let mut body: Vec<types::parse_nodes::Statement> = vec![];
+
+        while !self.peek_expect(&types::tokens::TokenType::RBracket) {
+            let item = match self.current_type() {
+                types::tokens::TokenType::KwFn => self.parse_function_satement(),
+                types::tokens::TokenType::KwLet => self.parse_let_statement(),
+                types::tokens::TokenType::KwConst => self.parse_const_statement(),
+                types::tokens::TokenType::KwStruct => self.parse_struct_statement(),
+                types::tokens::TokenType::KwImpl => self.parse_impl_statement(),
+                types::tokens::TokenType::KwNs => self.parse_namespace_statement(),
+                types::tokens::TokenType::KwPub => self.parse_public_statement(),
+                _ => {
+                    self.errors.insert(
+                        self.errors.len(),
+                        handlers::error_handler::Error::expected_error(
+                            "a function, a variable, a constant, a struct, a impl, a namespace or '}'",
+                            self.current(),
+                            handlers::message_handler::Issuer::Parser,
+                        ),
+                    );
+                    None
+                }
+            };
+
+            body.push(item?);
+
+            // The last token of the item
+            self.advance();
+        }
+
+        Some(types::parse_nodes::Statement::Namespace {
+            start: ns_token.position,
+            public: false,
+            name,
+            body: Box::new(body),
+        })
+    }
+
+    /// Parse and return a statement that can be used inside a code block
+    fn parse_statement(
+        &mut self,
+        is_loop: &types::parse_nodes::Loop,
+    ) -> Option<types::parse_nodes::Statement> {
+        match self.current_type() {
+            types::tokens::TokenType::KwLet => self.parse_let_statement(),
+            types::tokens::TokenType::KwConst => self.parse_const_statement(),
+            types::tokens::TokenType::KwReturn => self.parse_return_statement(),
+            types::tokens::TokenType::KwIf => self.parse_if_statement(is_loop),
+            types::tokens::TokenType::KwWhile => self.parse_while_statement(),
+            types::tokens::TokenType::KwFor => self.parse_for_statement(is_loop),
+            types::tokens::TokenType::KwBreak | types::tokens::TokenType::KwContinue => {
+                self.parse_loop_control_statement(is_loop)
+            }
+            types::tokens::TokenType::LBracket => {
+                let start = self.current().position.to_owned();
+                let body = self.parse_block_statement(is_loop)?;
+
+                Some(types::parse_nodes::Statement::Block {
+                    start,
+                    body: Box::new(body),
+                })
+            }
+            // A identifier followed by a assignment operator, or a function call
+            types::tokens::TokenType::Identifier => self.parse_name_statement(),
+            _ => {
+                self.errors.insert(
+                    self.errors.len(),
+                    handlers::error_handler::Error::expected_error(
+                        "a statement",
+                        self.current(),
+                        handlers::message_handler::Issuer::Parser,
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// While doesn't reaches EOF parse tokens
+    pub fn parse_tokens(&mut self) {
+        while !self.peek_expect(&types::tokens::TokenType::Eof) {
+            // Parse and return a statement
+            let ast_node: Option<types::parse_nodes::Statement> = match &self.current_type() {
+                types::tokens::TokenType::KwFn => self.parse_function_satement(),
+                types::tokens::TokenType::KwUse => self.parse_use_statement(),
+                types::tokens::TokenType::KwStruct => self.parse_struct_statement(),
+                types::tokens::TokenType::KwImpl => self.parse_impl_statement(),
+                types::tokens::TokenType::KwNs => self.parse_namespace_statement(),
+                types::tokens::TokenType::KwPub => self.parse_public_statement(),
+                _ => self.parse_statement(&types::parse_nodes::Loop::No),
             };
 
             // If a parse function find a error, it will return nothing
-            if ast_node.is_some() {
-                self.push_statement(ast_node.unwrap());
+            if let Some(ast_node) = ast_node {
+                self.push_statement(ast_node);
             } else {
                 break;
             }
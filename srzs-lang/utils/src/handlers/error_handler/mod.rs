@@ -1,9 +1,44 @@
+use crate::backend::bytecode;
 use crate::{handlers, types};
+use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum ErrorCode {
     E001, // Invalid token
     E002, // Unexpected token
+    E003, // Unsupported construct in code generation
+    E004, // Undefined variable
+    E005, // Undefined function
+    E006, // Invalid literal
+    E007, // Loop control outside of a loop
+    E008, // Wrong number of arguments
+    E009, // Return outside of a function
+    E010, // Stack underflow
+    E011, // Stack index out of bounds
+    E012, // Type mismatch
+    E013, // Division by zero
+    E014, // Integer overflow
+    E015, // Unknown function
+    E016, // Wrong number of arguments at runtime
+    E017, // Return without a function call
+    E018, // Source file can't be read
+    E019, // Native function failed
+    E020, // Unresolved import
+    E021, // Index out of bounds
+    E022, // Circular import
+    E023, // Private item used from another module
+    E024, // Invalid constant
+    E025, // Undefined struct
+    E026, // Unknown struct field
+    E027, // Invalid struct declaration or literal
+    E028, // Undefined method
+    E029, // Mismatched types
+    E030, // Invalid type
+    E031, // Name declared more than once in a scope
+    E032, // Assignment to a immutable variable
+    E033, // Variable used before it has a value
+    E034, // Function end reached without returning its value
+    E035, // Stack overflow
 }
 
 #[derive(Debug, Clone)]
@@ -18,6 +53,12 @@ impl BaseError {
     }
 }
 
+impl fmt::Display for BaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}] {}", self.code, self.message)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ScriptError {
     base: BaseError,
@@ -33,10 +74,178 @@ impl ScriptError {
     }
 }
 
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}:{})",
+            self.base,
+            self.position.line + 1,
+            self.position.column + 1
+        )
+    }
+}
+
+/// What went wrong while running bytecode
+#[derive(Debug, Clone)]
+pub enum RuntimeErrorKind {
+    // A instruction needed more values than the stack has
+    StackUnderflow,
+    // A instruction referenced a slot that doesn't exist in a stack
+    BadIndex(bytecode::ValueScope, usize),
+    // A operation received values of types it can't handle
+    TypeMismatch(String),
+    // A int or float division or rest with zero as the right operand
+    DivisionByZero,
+    // A int operation with a result out of the int range
+    IntegerOverflow(String),
+    // A call to a function id that isn't loaded
+    UnknownFunction(usize),
+    // A function called with the wrong number of arguments
+    ArityMismatch(String),
+    // A return without a function call to return from
+    NoFrame,
+    // A host function returned a error
+    Native(String),
+    // A array or str index out of its length -> (index, length)
+    OutOfBounds(i32, usize),
+    // A struct id that isn't loaded
+    UnknownStruct(usize),
+    // A struct without the field -> (struct, field)
+    UnknownField(String, String),
+    // A method call to a struct without the method -> struct::method
+    UnknownMethod(String),
+    // A call deeper than the call depth limit -> the called function
+    StackOverflow(String),
+}
+
+impl RuntimeErrorKind {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            RuntimeErrorKind::StackUnderflow => ErrorCode::E010,
+            RuntimeErrorKind::BadIndex(..) => ErrorCode::E011,
+            RuntimeErrorKind::TypeMismatch(_) => ErrorCode::E012,
+            RuntimeErrorKind::DivisionByZero => ErrorCode::E013,
+            RuntimeErrorKind::IntegerOverflow(_) => ErrorCode::E014,
+            RuntimeErrorKind::UnknownFunction(_) => ErrorCode::E015,
+            RuntimeErrorKind::ArityMismatch(_) => ErrorCode::E016,
+            RuntimeErrorKind::NoFrame => ErrorCode::E017,
+            RuntimeErrorKind::Native(_) => ErrorCode::E019,
+            RuntimeErrorKind::OutOfBounds(..) => ErrorCode::E021,
+            RuntimeErrorKind::UnknownStruct(_) => ErrorCode::E025,
+            RuntimeErrorKind::UnknownField(..) => ErrorCode::E026,
+            RuntimeErrorKind::UnknownMethod(_) => ErrorCode::E028,
+            RuntimeErrorKind::StackOverflow(_) => ErrorCode::E035,
+        }
+    }
+}
+
+impl fmt::Display for RuntimeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeErrorKind::StackUnderflow => write!(f, "stack underflow."),
+            RuntimeErrorKind::BadIndex(scope, index) => {
+                write!(f, "no value at index {} of the {:?} stack.", index, scope)
+            }
+            RuntimeErrorKind::TypeMismatch(message) => write!(f, "type mismatch: {}.", message),
+            RuntimeErrorKind::DivisionByZero => write!(f, "division by zero."),
+            RuntimeErrorKind::IntegerOverflow(operation) => {
+                write!(f, "integer overflow in \"{}\".", operation)
+            }
+            RuntimeErrorKind::UnknownFunction(id) => write!(f, "no function with id {}.", id),
+            RuntimeErrorKind::ArityMismatch(message) => write!(f, "{}.", message),
+            RuntimeErrorKind::NoFrame => write!(f, "return without a function call."),
+            RuntimeErrorKind::Native(message) => write!(f, "{}.", message),
+            RuntimeErrorKind::OutOfBounds(index, length) => {
+                write!(f, "index {} is out of bounds for length {}.", index, length)
+            }
+            RuntimeErrorKind::UnknownStruct(id) => write!(f, "no struct with id {}.", id),
+            RuntimeErrorKind::UnknownField(name, field) => {
+                write!(f, "struct \"{}\" has no field \"{}\".", name, field)
+            }
+            RuntimeErrorKind::UnknownMethod(method) => {
+                write!(f, "method \"{}\" is not defined.", method)
+            }
+            RuntimeErrorKind::StackOverflow(function) => {
+                write!(
+                    f,
+                    "stack overflow calling \"{}\", too many nested calls.",
+                    function
+                )
+            }
+        }
+    }
+}
+
+/// Where a instruction is in the bytecode
+#[derive(Debug, Clone)]
+pub struct BytecodeLocation {
+    // The function name, none for the global code
+    pub function: Option<String>,
+    // Index of the instruction in its scope
+    pub instruction: usize,
+}
+
+impl fmt::Display for BytecodeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.function {
+            Some(function) => write!(f, "{}:{}", function, self.instruction),
+            None => write!(f, "global:{}", self.instruction),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    base: BaseError,
+    kind: RuntimeErrorKind,
+    location: BytecodeLocation,
+}
+
+impl RuntimeError {
+    pub fn new(kind: RuntimeErrorKind, location: BytecodeLocation) -> Self {
+        let message = handlers::message_handler::Message::new(
+            kind.to_string().as_str(),
+            handlers::message_handler::Issuer::Runtime,
+        );
+
+        Self {
+            base: BaseError::new(message, kind.code()),
+            kind,
+            location,
+        }
+    }
+
+    pub fn kind(&self) -> &RuntimeErrorKind {
+        &self.kind
+    }
+
+    pub fn location(&self) -> &BytecodeLocation {
+        &self.location
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.base, self.location)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Error {
     Error(BaseError),
     ScriptError(ScriptError),
+    RuntimeError(RuntimeError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Error(error) => write!(f, "{}", error),
+            Error::ScriptError(error) => write!(f, "{}", error),
+            Error::RuntimeError(error) => write!(f, "{}", error),
+        }
+    }
 }
 
 impl Error {
@@ -61,6 +270,19 @@ impl Error {
         Error::ScriptError(script_error)
     }
 
+    /// Create a script error with the given code at the given position
+    pub fn script_error(
+        content: &str,
+        code: ErrorCode,
+        position: &types::others::Position,
+        from: handlers::message_handler::Issuer,
+    ) -> Error {
+        let message = handlers::message_handler::Message::new(content, from);
+        let base_error = BaseError::new(message, code);
+
+        Error::ScriptError(ScriptError::new(base_error, position.line, position.column))
+    }
+
     pub fn expected_or_error(
         expected: &str,
         expected_type: &types::tokens::TokenType,
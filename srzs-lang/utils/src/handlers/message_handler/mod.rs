@@ -1,7 +1,24 @@
+use std::fmt;
+
 #[derive(Debug, Clone)]
 pub enum Issuer {
     Lexer,
     Parser,
+    Compiler,
+    Runtime,
+    Engine,
+}
+
+impl fmt::Display for Issuer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issuer::Lexer => write!(f, "lexer"),
+            Issuer::Parser => write!(f, "parser"),
+            Issuer::Compiler => write!(f, "compiler"),
+            Issuer::Runtime => write!(f, "runtime"),
+            Issuer::Engine => write!(f, "engine"),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -18,3 +35,9 @@ impl Message {
         }
     }
 }
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.from, self.content)
+    }
+}
@@ -1,4 +1,7 @@
-#[derive(Debug, Clone)]
+use crate::types;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum BuiltInTypes {
     Null,
     Int,
@@ -8,4 +11,101 @@ pub enum BuiltInTypes {
     Str,
     Void,
     Any,
+    /// A struct by its name, the compiler resolves it to the full name of the declaration
+    Struct(String),
+}
+
+impl fmt::Display for BuiltInTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuiltInTypes::Null => write!(f, "null"),
+            BuiltInTypes::Int => write!(f, "int"),
+            BuiltInTypes::Float => write!(f, "float"),
+            BuiltInTypes::Bool => write!(f, "bool"),
+            BuiltInTypes::Char => write!(f, "char"),
+            BuiltInTypes::Str => write!(f, "str"),
+            BuiltInTypes::Void => write!(f, "void"),
+            BuiltInTypes::Any => write!(f, "any"),
+            BuiltInTypes::Struct(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl BuiltInTypes {
+    /// If a value of the found type can be given where this type is expected
+    ///
+    /// - "any" on either side is only known when it runs, the value is checked then.
+    /// - A int is promoted where a float is expected.
+    pub fn accepts(&self, found: &BuiltInTypes) -> bool {
+        match (self, found) {
+            (BuiltInTypes::Any, _) | (_, BuiltInTypes::Any) => true,
+            (BuiltInTypes::Float, BuiltInTypes::Int) => true,
+            (expected, found) => expected == found,
+        }
+    }
+
+    /// If the type is a int or a float
+    fn is_number(&self) -> bool {
+        matches!(self, BuiltInTypes::Int | BuiltInTypes::Float)
+    }
+
+    /// The type of a binary operation, or nothing if its operands can't be used with the operator
+    pub fn binary(
+        operator: &types::tokens::TokenType,
+        left: &BuiltInTypes,
+        right: &BuiltInTypes,
+    ) -> Option<BuiltInTypes> {
+        match operator {
+            // Any two values can be compared for equality, and any value is true or false
+            types::tokens::TokenType::OpEquals
+            | types::tokens::TokenType::OpNotEquals
+            | types::tokens::TokenType::OpAnd
+            | types::tokens::TokenType::OpOr => Some(BuiltInTypes::Bool),
+            types::tokens::TokenType::OpSmallerThan
+            | types::tokens::TokenType::OpGreaterThan
+            | types::tokens::TokenType::OpSmallerOrEqualsThan
+            | types::tokens::TokenType::OpGreaterOrEqualsThan => match (left, right) {
+                (BuiltInTypes::Any, _) | (_, BuiltInTypes::Any) => Some(BuiltInTypes::Bool),
+                (left, right) if left.is_number() && right.is_number() => Some(BuiltInTypes::Bool),
+                (BuiltInTypes::Str, BuiltInTypes::Str)
+                | (BuiltInTypes::Char, BuiltInTypes::Char) => Some(BuiltInTypes::Bool),
+                _ => None,
+            },
+            _ => match (left, right) {
+                (BuiltInTypes::Int, BuiltInTypes::Int) => Some(BuiltInTypes::Int),
+                (left, right) if left.is_number() && right.is_number() => Some(BuiltInTypes::Float),
+                (BuiltInTypes::Str, BuiltInTypes::Str)
+                    if operator.eq(&types::tokens::TokenType::OpPlus) =>
+                {
+                    Some(BuiltInTypes::Str)
+                }
+                // The other operand must still be something the operator can take
+                (BuiltInTypes::Any, other) | (other, BuiltInTypes::Any)
+                    if other.is_number()
+                        || other.eq(&BuiltInTypes::Any)
+                        || (other.eq(&BuiltInTypes::Str)
+                            && operator.eq(&types::tokens::TokenType::OpPlus)) =>
+                {
+                    Some(BuiltInTypes::Any)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// The type of a unary operation, or nothing if its operand can't be used with the operator
+    pub fn unary(
+        operator: &types::tokens::TokenType,
+        operand: &BuiltInTypes,
+    ) -> Option<BuiltInTypes> {
+        match operator {
+            types::tokens::TokenType::OpNot => Some(BuiltInTypes::Bool),
+            _ => match operand {
+                BuiltInTypes::Int | BuiltInTypes::Float | BuiltInTypes::Any => {
+                    Some(operand.to_owned())
+                }
+                _ => None,
+            },
+        }
+    }
 }
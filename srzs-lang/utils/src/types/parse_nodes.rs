@@ -6,21 +6,48 @@ pub enum Statement {
         start: types::others::Position,
         body: Box<Vec<Statement>>,
     },
+    Use {
+        start: types::others::Position,
+        path: String,
+    },
+    Namespace {
+        start: types::others::Position,
+        public: bool,
+        name: String,
+        body: Box<Vec<Statement>>,
+    },
     LetDeclaration {
         start: types::others::Position,
+        public: bool,
         name: String,
         kind: LetDeclarationKind,
-        r#type: types::built_in_types::BuiltInTypes,
+        /// None when the type comes from the value
+        r#type: Option<types::built_in_types::BuiltInTypes>,
         value: Option<Expression>,
     },
     ConstantDeclaration {
         start: types::others::Position,
+        public: bool,
         name: Option<String>,
-        r#type: types::built_in_types::BuiltInTypes,
+        /// None when the type comes from the value
+        r#type: Option<types::built_in_types::BuiltInTypes>,
         value: Expression,
     },
+    StructDeclaration {
+        start: types::others::Position,
+        public: bool,
+        name: String,
+        fields: Vec<StructField>,
+    },
+    Impl {
+        start: types::others::Position,
+        /// The struct of the methods
+        name: String,
+        methods: Box<Vec<Statement>>,
+    },
     FuctionDeclaration {
         start: types::others::Position,
+        public: bool,
         name: String,
         r#type: types::built_in_types::BuiltInTypes,
         params: Vec<FuncParam>,
@@ -63,14 +90,30 @@ pub enum Statement {
     },
     Return {
         start: types::others::Position,
-        expression: Expression,
+        expression: Option<Expression>,
     },
     VariableAlteration {
+        start: types::others::Position,
         name: String,
         operator: types::tokens::TokenType,
         value: Expression,
     },
-    FunctionCall(Expression),
+    FieldAlteration {
+        start: types::others::Position,
+        name: String,
+        /// The path of fields from the variable to the altered one -> name.fields[0].fields[1]
+        fields: Vec<String>,
+        operator: types::tokens::TokenType,
+        value: Expression,
+    },
+    FunctionCall {
+        start: types::others::Position,
+        expression: Expression,
+    },
+    Block {
+        start: types::others::Position,
+        body: Box<Vec<Statement>>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -85,6 +128,12 @@ pub struct FuncParam {
     pub r#type: types::built_in_types::BuiltInTypes,
 }
 
+#[derive(Debug, Clone)]
+pub struct StructField {
+    pub name: String,
+    pub r#type: types::built_in_types::BuiltInTypes,
+}
+
 #[derive(Debug, Clone)]
 pub enum LetDeclarationKind {
     Mutable,
@@ -132,6 +181,19 @@ pub enum Expression {
         name: String,
         arguments: Option<Box<Vec<Expression>>>,
     },
+    MethodCall {
+        object: Box<Expression>,
+        method: String,
+        arguments: Option<Box<Vec<Expression>>>,
+    },
+    StructLiteral {
+        name: String,
+        fields: Box<Vec<(String, Expression)>>,
+    },
+    FieldAcess {
+        object: Box<Expression>,
+        field: String,
+    },
 }
 
 #[derive(Debug, Clone)]
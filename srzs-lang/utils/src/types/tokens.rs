@@ -1,4 +1,4 @@
-use crate::frontend::lexer::{new_line_callback, word_callback};
+use crate::frontend::lexer::{comment_callback, new_line_callback, word_callback};
 use crate::types;
 use logos::Logos;
 
@@ -54,8 +54,8 @@ pub enum TokenType {
     #[token("}", word_callback)]
     RBracket,
 
-    #[token("-#", word_callback)]
-    OpenComment,
+    #[token("-#", comment_callback)]
+    Comment,
 
     #[token("#-", word_callback)]
     CloseComment,
@@ -67,6 +67,9 @@ pub enum TokenType {
     #[token("use", word_callback)]
     KwUse,
 
+    #[token("pub", word_callback)]
+    KwPub,
+
     #[token("let", word_callback)]
     KwLet,
 
@@ -79,6 +82,9 @@ pub enum TokenType {
     #[token("struct", word_callback)]
     KwStruct,
 
+    #[token("impl", word_callback)]
+    KwImpl,
+
     #[token("fn", word_callback)]
     KwFn,
 
@@ -91,12 +97,24 @@ pub enum TokenType {
     #[token("elif", word_callback)]
     KwElif,
 
+    #[token("while", word_callback)]
+    KwWhile,
+
+    #[token("for", word_callback)]
+    KwFor,
+
     #[token("is", word_callback)]
     KwIs,
 
     #[token("return", word_callback)]
     KwReturn,
 
+    #[token("break", word_callback)]
+    KwBreak,
+
+    #[token("continue", word_callback)]
+    KwContinue,
+
     // Built-in types
     #[token("null", word_callback)]
     TypeNull,
@@ -158,10 +176,10 @@ pub enum TokenType {
     #[token(">", word_callback)]
     OpGreaterThan,
 
-    #[token(">=", word_callback)]
+    #[token("<=", word_callback)]
     OpSmallerOrEqualsThan,
 
-    #[token("<=", word_callback)]
+    #[token(">=", word_callback)]
     OpGreaterOrEqualsThan,
 
     #[token("==", word_callback)]
@@ -206,10 +224,13 @@ pub enum TokenType {
     #[regex("[0-9]+", word_callback)]
     Number,
 
+    #[regex("[0-9]+\\.[0-9]+", word_callback)]
+    DecimalNumber,
+
     #[token("_", priority = 3, callback = word_callback)]
     SomehingElse,
 
-    #[regex(r#"'([^"\\]|\\["\\bnfrt]|u[a-fA-F0-9]{4})*'"#, word_callback)]
+    #[regex(r#"'([^'\\]|\\['"\\bnfrt0])'"#, word_callback)]
     CharLiteral,
 
     #[regex(r#""([^"\\]|\\["\\bnfrt]|u[a-fA-F0-9]{4})*""#, word_callback)]